@@ -0,0 +1,97 @@
+// Copyright 2025 Automattic
+//
+// This file is part of Gravatar Wasm Foreign Data Wrapper which is licensed under
+// the GNU General Public License v3.0.
+
+// Benchmarks of the per-row hot path: email hashing, qual extraction and
+// profile to cell mapping. Run natively with `--features bench`.
+//
+//   cargo bench --features bench
+
+#[allow(dead_code)]
+#[path = "../src/fdw_core.rs"]
+mod fdw_core;
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use fdw_core::{
+    demo_profile, extract_emails, hash_email, profile_cell, Cell, EmailNormalization, IdnPolicy, QualLike, TypeOid,
+    Value, JSON_FIELDS, TEXT_FIELDS,
+};
+
+// Emails of a large IN list, as a batch job would query them
+const IN_LIST_SIZE: usize = 1000;
+
+struct EmailQual(Value);
+
+impl QualLike for EmailQual {
+    fn field(&self) -> String {
+        "email".to_owned()
+    }
+    fn operator(&self) -> String {
+        "=".to_owned()
+    }
+    fn use_or(&self) -> bool {
+        matches!(self.0, Value::Array(_))
+    }
+    fn value(&self) -> Value {
+        self.0.clone()
+    }
+    fn param_id(&self) -> Option<u32> {
+        None
+    }
+}
+
+fn emails() -> Vec<String> {
+    (0..IN_LIST_SIZE).map(|n| format!("User.{}+news@Example.com", n)).collect()
+}
+
+fn hashing(c: &mut Criterion) {
+    let normalization = EmailNormalization {
+        strip_plus_tags: true,
+        idn: IdnPolicy::Punycode,
+    };
+    c.bench_function("hash_email", |b| b.iter(|| hash_email(black_box(" Test.User@Example.com "))));
+    c.bench_function("hash_email normalized", |b| {
+        b.iter(|| normalization.hash(black_box("test.user+news@bücher.example")))
+    });
+}
+
+fn qual_extraction(c: &mut Criterion) {
+    let cells = emails().into_iter().map(Cell::String).collect();
+    let in_list = [EmailQual(Value::Array(cells))];
+    c.bench_function("extract_emails in list", |b| b.iter(|| extract_emails(black_box(&in_list))));
+}
+
+fn mapping(c: &mut Criterion) {
+    let profile = demo_profile(&hash_email("test@example.com"));
+    let columns: Vec<(&str, TypeOid)> = TEXT_FIELDS
+        .iter()
+        .map(|field| (*field, TypeOid::String))
+        .chain(JSON_FIELDS.iter().map(|field| (*field, TypeOid::Json)))
+        .chain([("is_organization", TypeOid::Bool), ("number_verified_accounts", TypeOid::I64)])
+        .collect();
+    c.bench_function("profile_cell all columns", |b| {
+        b.iter(|| {
+            columns
+                .iter()
+                .map(|(column, type_oid)| profile_cell(black_box(&profile), column, *type_oid))
+                .collect::<Vec<_>>()
+        })
+    });
+    let body = profile.to_string();
+    c.bench_function("parse and map response", |b| {
+        b.iter(|| {
+            let profile: serde_json::Value = serde_json::from_str(black_box(&body)).unwrap();
+            columns
+                .iter()
+                .map(|(column, type_oid)| profile_cell(&profile, column, *type_oid))
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
+criterion_group!(benches, hashing, qual_extraction, mapping);
+criterion_main!(benches);
@@ -0,0 +1,775 @@
+// Copyright 2025 Automattic
+//
+// This file is part of Gravatar Wasm Foreign Data Wrapper which is licensed under
+// the GNU General Public License v3.0.
+
+// A fake Supabase Wrappers host. It loads the built FDW component under
+// wasmtime and serves the host interfaces from memory: canned HTTP responses,
+// a controllable clock, Vault secrets and captured log messages. Tests drive
+// the FDW routines through `Harness` the way Postgres would.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use wasmtime::component::{Component, Linker, Resource, ResourceTable};
+use wasmtime::{Config, Engine, Store};
+
+wasmtime::component::bindgen!({
+    path: ["../supabase-wrappers-wit", "../wit"],
+    world: "automattic:gravatar-fdw/gravatar",
+    with: {
+        "supabase:wrappers/types/row": FakeRow,
+        "supabase:wrappers/types/column": FakeColumn,
+        "supabase:wrappers/types/qual": FakeQual,
+        "supabase:wrappers/types/sort": FakeSort,
+        "supabase:wrappers/types/limit": FakeLimit,
+        "supabase:wrappers/types/options": FakeOptions,
+        "supabase:wrappers/types/context": FakeContext,
+    },
+    additional_derives: [PartialEq],
+});
+
+use supabase::wrappers::{http, jwt, stats, time, types, utils};
+pub use supabase::wrappers::types::{Cell, OptionsType, TypeOid, Value};
+
+// Rows pushed by the FDW during one iter_scan call
+#[derive(Default)]
+pub struct FakeRow {
+    cols: Vec<String>,
+    cells: Vec<Option<Cell>>,
+}
+
+pub struct FakeColumn {
+    name: String,
+    num: u32,
+    type_oid: TypeOid,
+}
+
+// A WHERE condition pushed down to the FDW
+#[derive(Clone)]
+pub struct FakeQual {
+    pub field: String,
+    pub operator: String,
+    pub value: Value,
+    pub use_or: bool,
+    // ID of the query parameter the value was evaluated from, as for
+    // `EXECUTE stmt('...')` or a nested loop join
+    pub param: Option<u32>,
+}
+
+impl FakeQual {
+    // `field = 'value'`
+    pub fn eq(field: &str, value: &str) -> Self {
+        Self {
+            field: field.to_owned(),
+            operator: "=".to_owned(),
+            value: Value::Cell(Cell::String(value.to_owned())),
+            use_or: false,
+            param: None,
+        }
+    }
+
+    // `field IN ('a', 'b', ...)`
+    pub fn any(field: &str, values: &[&str]) -> Self {
+        Self {
+            field: field.to_owned(),
+            operator: "=".to_owned(),
+            value: Value::Array(values.iter().map(|v| Cell::String(v.to_string())).collect()),
+            use_or: true,
+            param: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct FakeSort {
+    pub field: String,
+    pub field_no: u32,
+    pub reversed: bool,
+    pub nulls_first: bool,
+}
+
+#[derive(Clone)]
+pub struct FakeLimit {
+    pub count: i64,
+    pub offset: i64,
+}
+
+pub struct FakeOptions(HashMap<String, String>);
+
+pub struct FakeContext;
+
+// The statement a scan or modify runs for
+#[derive(Clone, Default)]
+pub struct Query {
+    pub table_options: HashMap<String, String>,
+    pub columns: Vec<(String, TypeOid)>,
+    pub quals: Vec<FakeQual>,
+    pub sorts: Vec<FakeSort>,
+    pub limit: Option<FakeLimit>,
+}
+
+impl Query {
+    // Query on a foreign table with the given `table` option and columns
+    pub fn table(table: &str, columns: &[(&str, TypeOid)]) -> Self {
+        Self {
+            table_options: HashMap::from([("table".to_owned(), table.to_owned())]),
+            columns: columns.iter().map(|(name, type_oid)| (name.to_string(), *type_oid)).collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_qual(mut self, qual: FakeQual) -> Self {
+        self.quals.push(qual);
+        self
+    }
+
+    pub fn with_option(mut self, key: &str, value: &str) -> Self {
+        self.table_options.insert(key.to_owned(), value.to_owned());
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct FakeResponse {
+    pub status_code: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl FakeResponse {
+    pub fn json(status_code: u16, body: &str) -> Self {
+        Self {
+            status_code,
+            headers: vec![("content-type".to_owned(), "application/json".to_owned())],
+            body: body.to_owned(),
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Info,
+    Notice,
+    Warning,
+    Error,
+}
+
+// State behind every host interface
+pub struct FakeHost {
+    table: ResourceTable,
+    pub server_options: HashMap<String, String>,
+    pub query: Query,
+    pub vault: HashMap<String, String>,
+    // responses by URL; unknown URLs answer 404
+    pub responses: HashMap<String, FakeResponse>,
+    // URLs whose requests fail at the network level
+    pub network_errors: Vec<String>,
+    // method and URL of every request, in order
+    pub requests: Vec<(String, String)>,
+    // headers of every request, in the order of `requests`
+    pub request_headers: Vec<Vec<(String, String)>>,
+    pub messages: Vec<(Level, String)>,
+    pub stats: HashMap<String, i64>,
+    pub metadata: HashMap<String, String>,
+    // fake clock, advanced by `sleep` instead of blocking
+    pub now_ms: i64,
+}
+
+impl Default for FakeHost {
+    fn default() -> Self {
+        let now_ms = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_millis() as i64);
+        Self {
+            table: ResourceTable::new(),
+            server_options: HashMap::new(),
+            query: Query::default(),
+            vault: HashMap::new(),
+            responses: HashMap::new(),
+            network_errors: Vec::new(),
+            requests: Vec::new(),
+            request_headers: Vec::new(),
+            messages: Vec::new(),
+            stats: HashMap::new(),
+            metadata: HashMap::new(),
+            now_ms,
+        }
+    }
+}
+
+impl FakeHost {
+    pub fn with_server_option(mut self, key: &str, value: &str) -> Self {
+        self.server_options.insert(key.to_owned(), value.to_owned());
+        self
+    }
+
+    pub fn with_response(mut self, url: &str, response: FakeResponse) -> Self {
+        self.responses.insert(url.to_owned(), response);
+        self
+    }
+
+    pub fn with_vault_secret(mut self, id: &str, value: &str) -> Self {
+        self.vault.insert(id.to_owned(), value.to_owned());
+        self
+    }
+
+    // Messages reported at the given level
+    pub fn messages_at(&self, level: Level) -> Vec<&str> {
+        self.messages
+            .iter()
+            .filter(|(l, _)| *l == level)
+            .map(|(_, msg)| msg.as_str())
+            .collect()
+    }
+
+    fn respond(&mut self, method: &str, req: http::Request) -> http::HttpResult {
+        self.requests.push((method.to_owned(), req.url.clone()));
+        self.request_headers.push(req.headers.clone());
+        if self.network_errors.contains(&req.url) {
+            return Err(format!("error sending request for url ({})", req.url));
+        }
+        let resp = self
+            .responses
+            .get(&req.url)
+            .cloned()
+            .unwrap_or_else(|| FakeResponse::json(404, r#"{"error":"Profile not found","code":"not_found"}"#));
+        Ok(http::Response {
+            url: req.url,
+            status_code: resp.status_code,
+            headers: resp.headers,
+            body: resp.body,
+        })
+    }
+
+    fn options(&self, options_type: OptionsType) -> FakeOptions {
+        match options_type {
+            OptionsType::Server => FakeOptions(self.server_options.clone()),
+            OptionsType::Table => FakeOptions(self.query.table_options.clone()),
+        }
+    }
+}
+
+impl types::Host for FakeHost {}
+
+impl types::HostRow for FakeHost {
+    fn new(&mut self) -> Resource<FakeRow> {
+        self.table.push(FakeRow::default()).expect("resource table full")
+    }
+
+    fn cols(&mut self, self_: Resource<FakeRow>) -> Vec<String> {
+        self.table.get(&self_).expect("valid row").cols.clone()
+    }
+
+    fn cells(&mut self, self_: Resource<FakeRow>) -> Vec<Option<Cell>> {
+        self.table.get(&self_).expect("valid row").cells.clone()
+    }
+
+    fn push(&mut self, self_: Resource<FakeRow>, cell: Option<Cell>) {
+        self.table.get_mut(&self_).expect("valid row").cells.push(cell);
+    }
+
+    fn drop(&mut self, rep: Resource<FakeRow>) -> wasmtime::Result<()> {
+        self.table.delete(rep)?;
+        Ok(())
+    }
+}
+
+impl types::HostColumn for FakeHost {
+    fn new(&mut self, index: u32) -> Resource<FakeColumn> {
+        let (name, type_oid) = self.query.columns[index as usize].clone();
+        let column = FakeColumn {
+            name,
+            num: index + 1,
+            type_oid,
+        };
+        self.table.push(column).expect("resource table full")
+    }
+
+    fn name(&mut self, self_: Resource<FakeColumn>) -> String {
+        self.table.get(&self_).expect("valid column").name.clone()
+    }
+
+    fn num(&mut self, self_: Resource<FakeColumn>) -> u32 {
+        self.table.get(&self_).expect("valid column").num
+    }
+
+    fn type_oid(&mut self, self_: Resource<FakeColumn>) -> TypeOid {
+        self.table.get(&self_).expect("valid column").type_oid
+    }
+
+    fn drop(&mut self, rep: Resource<FakeColumn>) -> wasmtime::Result<()> {
+        self.table.delete(rep)?;
+        Ok(())
+    }
+}
+
+impl types::HostQual for FakeHost {
+    fn new(&mut self, index: u32) -> Resource<FakeQual> {
+        let qual = self.query.quals[index as usize].clone();
+        self.table.push(qual).expect("resource table full")
+    }
+
+    fn field(&mut self, self_: Resource<FakeQual>) -> String {
+        self.table.get(&self_).expect("valid qual").field.clone()
+    }
+
+    fn operator(&mut self, self_: Resource<FakeQual>) -> String {
+        self.table.get(&self_).expect("valid qual").operator.clone()
+    }
+
+    fn value(&mut self, self_: Resource<FakeQual>) -> Value {
+        self.table.get(&self_).expect("valid qual").value.clone()
+    }
+
+    fn use_or(&mut self, self_: Resource<FakeQual>) -> bool {
+        self.table.get(&self_).expect("valid qual").use_or
+    }
+
+    fn param(&mut self, self_: Resource<FakeQual>) -> Option<types::Param> {
+        let id = self.table.get(&self_).expect("valid qual").param?;
+        // text
+        Some(types::Param { id, type_oid: 25 })
+    }
+
+    fn deparse(&mut self, self_: Resource<FakeQual>) -> String {
+        let qual = self.table.get(&self_).expect("valid qual");
+        format!("{} {} {:?}", qual.field, qual.operator, qual.value)
+    }
+
+    fn drop(&mut self, rep: Resource<FakeQual>) -> wasmtime::Result<()> {
+        self.table.delete(rep)?;
+        Ok(())
+    }
+}
+
+impl types::HostSort for FakeHost {
+    fn new(&mut self, index: u32) -> Resource<FakeSort> {
+        let sort = self.query.sorts[index as usize].clone();
+        self.table.push(sort).expect("resource table full")
+    }
+
+    fn field(&mut self, self_: Resource<FakeSort>) -> String {
+        self.table.get(&self_).expect("valid sort").field.clone()
+    }
+
+    fn field_no(&mut self, self_: Resource<FakeSort>) -> u32 {
+        self.table.get(&self_).expect("valid sort").field_no
+    }
+
+    fn reversed(&mut self, self_: Resource<FakeSort>) -> bool {
+        self.table.get(&self_).expect("valid sort").reversed
+    }
+
+    fn nulls_first(&mut self, self_: Resource<FakeSort>) -> bool {
+        self.table.get(&self_).expect("valid sort").nulls_first
+    }
+
+    fn collate(&mut self, _self_: Resource<FakeSort>) -> Option<String> {
+        None
+    }
+
+    fn deparse(&mut self, self_: Resource<FakeSort>) -> String {
+        let sort = self.table.get(&self_).expect("valid sort");
+        format!("{} {}", sort.field, if sort.reversed { "desc" } else { "asc" })
+    }
+
+    fn deparse_with_collate(&mut self, self_: Resource<FakeSort>) -> String {
+        self.deparse(self_)
+    }
+
+    fn drop(&mut self, rep: Resource<FakeSort>) -> wasmtime::Result<()> {
+        self.table.delete(rep)?;
+        Ok(())
+    }
+}
+
+impl types::HostLimit for FakeHost {
+    fn new(&mut self) -> Resource<FakeLimit> {
+        let limit = self.query.limit.clone().unwrap_or(FakeLimit { count: 0, offset: 0 });
+        self.table.push(limit).expect("resource table full")
+    }
+
+    fn count(&mut self, self_: Resource<FakeLimit>) -> i64 {
+        self.table.get(&self_).expect("valid limit").count
+    }
+
+    fn offset(&mut self, self_: Resource<FakeLimit>) -> i64 {
+        self.table.get(&self_).expect("valid limit").offset
+    }
+
+    fn deparse(&mut self, self_: Resource<FakeLimit>) -> String {
+        let limit = self.table.get(&self_).expect("valid limit");
+        format!("limit {} offset {}", limit.count, limit.offset)
+    }
+
+    fn drop(&mut self, rep: Resource<FakeLimit>) -> wasmtime::Result<()> {
+        self.table.delete(rep)?;
+        Ok(())
+    }
+}
+
+impl types::HostOptions for FakeHost {
+    fn new(&mut self, options_type: OptionsType) -> Resource<FakeOptions> {
+        let options = self.options(options_type);
+        self.table.push(options).expect("resource table full")
+    }
+
+    fn get(&mut self, self_: Resource<FakeOptions>, key: String) -> Option<String> {
+        self.table.get(&self_).expect("valid options").0.get(&key).cloned()
+    }
+
+    fn require(&mut self, self_: Resource<FakeOptions>, key: String) -> Result<String, types::FdwError> {
+        self.get(self_, key.clone())
+            .ok_or_else(|| format!("required option \"{}\" is not specified", key))
+    }
+
+    fn require_or(&mut self, self_: Resource<FakeOptions>, key: String, default: String) -> String {
+        self.get(self_, key).unwrap_or(default)
+    }
+
+    fn drop(&mut self, rep: Resource<FakeOptions>) -> wasmtime::Result<()> {
+        self.table.delete(rep)?;
+        Ok(())
+    }
+}
+
+impl types::HostContext for FakeHost {
+    fn new(&mut self) -> Resource<FakeContext> {
+        self.table.push(FakeContext).expect("resource table full")
+    }
+
+    fn get_options(&mut self, _self_: Resource<FakeContext>, options_type: OptionsType) -> Resource<FakeOptions> {
+        let options = self.options(options_type);
+        self.table.push(options).expect("resource table full")
+    }
+
+    fn get_quals(&mut self, _self_: Resource<FakeContext>) -> Vec<Resource<FakeQual>> {
+        let quals = self.query.quals.clone();
+        quals
+            .into_iter()
+            .map(|qual| self.table.push(qual).expect("resource table full"))
+            .collect()
+    }
+
+    fn get_columns(&mut self, _self_: Resource<FakeContext>) -> Vec<Resource<FakeColumn>> {
+        (0..self.query.columns.len() as u32)
+            .map(|index| types::HostColumn::new(self, index))
+            .collect()
+    }
+
+    fn get_sorts(&mut self, _self_: Resource<FakeContext>) -> Vec<Resource<FakeSort>> {
+        (0..self.query.sorts.len() as u32)
+            .map(|index| types::HostSort::new(self, index))
+            .collect()
+    }
+
+    fn get_limit(&mut self, _self_: Resource<FakeContext>) -> Option<Resource<FakeLimit>> {
+        self.query.limit.is_some().then(|| types::HostLimit::new(self))
+    }
+
+    fn drop(&mut self, rep: Resource<FakeContext>) -> wasmtime::Result<()> {
+        self.table.delete(rep)?;
+        Ok(())
+    }
+}
+
+impl http::Host for FakeHost {
+    fn get(&mut self, req: http::Request) -> http::HttpResult {
+        self.respond("GET", req)
+    }
+
+    fn post(&mut self, req: http::Request) -> http::HttpResult {
+        self.respond("POST", req)
+    }
+
+    fn put(&mut self, req: http::Request) -> http::HttpResult {
+        self.respond("PUT", req)
+    }
+
+    fn patch(&mut self, req: http::Request) -> http::HttpResult {
+        self.respond("PATCH", req)
+    }
+
+    fn delete(&mut self, req: http::Request) -> http::HttpResult {
+        self.respond("DELETE", req)
+    }
+
+    fn error_for_status(&mut self, resp: http::Response) -> Result<(), http::HttpError> {
+        if resp.status_code >= 400 {
+            return Err(format!("HTTP status {}", resp.status_code));
+        }
+        Ok(())
+    }
+}
+
+impl time::Host for FakeHost {
+    fn epoch_secs(&mut self) -> i64 {
+        self.now_ms / 1000
+    }
+
+    fn parse_from_rfc3339(&mut self, _s: String) -> time::TimeResult {
+        Err("parse_from_rfc3339 is not available in the test host".to_owned())
+    }
+
+    fn parse_from_str(&mut self, _s: String, _fmt: String) -> time::TimeResult {
+        Err("parse_from_str is not available in the test host".to_owned())
+    }
+
+    fn epoch_ms_to_rfc3339(&mut self, _msecs: i64) -> Result<String, time::TimeError> {
+        Err("epoch_ms_to_rfc3339 is not available in the test host".to_owned())
+    }
+
+    fn sleep(&mut self, millis: u64) {
+        self.now_ms += millis as i64;
+    }
+}
+
+impl utils::Host for FakeHost {
+    fn report_info(&mut self, msg: String) {
+        self.messages.push((Level::Info, msg));
+    }
+
+    fn report_notice(&mut self, msg: String) {
+        self.messages.push((Level::Notice, msg));
+    }
+
+    fn report_warning(&mut self, msg: String) {
+        self.messages.push((Level::Warning, msg));
+    }
+
+    fn report_error(&mut self, msg: String) {
+        self.messages.push((Level::Error, msg));
+    }
+
+    fn cell_to_string(&mut self, cell: Option<Cell>) -> String {
+        match cell {
+            None => "null".to_owned(),
+            Some(Cell::String(s)) | Some(Cell::Json(s)) => s,
+            Some(cell) => format!("{:?}", cell),
+        }
+    }
+
+    fn get_vault_secret(&mut self, secret_id: String) -> Option<String> {
+        self.vault.get(&secret_id).cloned()
+    }
+}
+
+impl stats::Host for FakeHost {
+    fn inc_stats(&mut self, fdw_name: String, metric: stats::Metric, inc: i64) {
+        *self.stats.entry(format!("{}/{:?}", fdw_name, metric)).or_default() += inc;
+    }
+
+    fn get_metadata(&mut self, fdw_name: String) -> stats::Metadata {
+        self.metadata.get(&fdw_name).cloned()
+    }
+
+    fn set_metadata(&mut self, fdw_name: String, metadata: stats::Metadata) {
+        match metadata {
+            Some(metadata) => self.metadata.insert(fdw_name, metadata),
+            None => self.metadata.remove(&fdw_name),
+        };
+    }
+}
+
+impl jwt::Host for FakeHost {
+    fn encode(&mut self, _payload: Vec<(String, String)>, _algo: String, _key: String, _ttl_hours: u32) -> jwt::JwtResult {
+        Err("jwt is not available in the test host".to_owned())
+    }
+}
+
+// Rows returned by a scan, one cell per requested column
+pub type Rows = Vec<Vec<Option<Cell>>>;
+
+// The FDW component instantiated against a `FakeHost`. Routine calls return
+// the FDW's own result inside the outer wasmtime result, so tests can assert
+// on user-facing error messages.
+pub struct Harness {
+    store: Store<FakeHost>,
+    fdw: Gravatar,
+}
+
+impl Harness {
+    pub fn new(wasm: impl AsRef<Path>, host: FakeHost) -> anyhow::Result<Self> {
+        let mut config = Config::new();
+        config.wasm_component_model(true);
+        let engine = Engine::new(&config)?;
+        let component = Component::from_file(&engine, wasm)?;
+        let mut linker = Linker::new(&engine);
+        Gravatar::add_to_linker(&mut linker, |host: &mut FakeHost| host)?;
+        let mut store = Store::new(&engine, host);
+        let fdw = Gravatar::instantiate(&mut store, &component, &linker)?;
+        Ok(Self { store, fdw })
+    }
+
+    pub fn host(&self) -> &FakeHost {
+        self.store.data()
+    }
+
+    pub fn host_mut(&mut self) -> &mut FakeHost {
+        self.store.data_mut()
+    }
+
+    pub fn host_version_requirement(&mut self) -> anyhow::Result<String> {
+        self.fdw.supabase_wrappers_routines().call_host_version_requirement(&mut self.store)
+    }
+
+    pub fn init(&mut self) -> anyhow::Result<Result<(), String>> {
+        let ctx = self.store.data_mut().table.push(FakeContext)?;
+        let result = self
+            .fdw
+            .supabase_wrappers_routines()
+            .call_init(&mut self.store, Resource::new_borrow(ctx.rep()));
+        self.store.data_mut().table.delete(ctx)?;
+        result
+    }
+
+    // Run begin_scan, iter_scan until exhausted, and end_scan
+    pub fn scan(&mut self, query: Query) -> anyhow::Result<Result<Rows, String>> {
+        self.store.data_mut().query = query;
+        let ctx = self.store.data_mut().table.push(FakeContext)?;
+        let result = self.run_scan(ctx.rep());
+        self.store.data_mut().table.delete(ctx)?;
+        result
+    }
+
+    // Like `scan`, with a re_scan after the first pass, as the inner side of
+    // a nested loop join does. Returns the rows of both passes.
+    pub fn scan_and_rescan(&mut self, query: Query) -> anyhow::Result<Result<(Rows, Rows), String>> {
+        self.store.data_mut().query = query;
+        let ctx = self.store.data_mut().table.push(FakeContext)?;
+        let result = self.run_scan_and_rescan(ctx.rep());
+        self.store.data_mut().table.delete(ctx)?;
+        result
+    }
+
+    fn run_scan(&mut self, ctx: u32) -> anyhow::Result<Result<Rows, String>> {
+        if let Err(err) = self
+            .fdw
+            .supabase_wrappers_routines()
+            .call_begin_scan(&mut self.store, Resource::new_borrow(ctx))?
+        {
+            return Ok(Err(err));
+        }
+        let rows = match self.iter_rows(ctx)? {
+            Ok(rows) => rows,
+            Err(err) => return Ok(Err(err)),
+        };
+        if let Err(err) = self
+            .fdw
+            .supabase_wrappers_routines()
+            .call_end_scan(&mut self.store, Resource::new_borrow(ctx))?
+        {
+            return Ok(Err(err));
+        }
+        Ok(Ok(rows))
+    }
+
+    fn run_scan_and_rescan(&mut self, ctx: u32) -> anyhow::Result<Result<(Rows, Rows), String>> {
+        if let Err(err) = self
+            .fdw
+            .supabase_wrappers_routines()
+            .call_begin_scan(&mut self.store, Resource::new_borrow(ctx))?
+        {
+            return Ok(Err(err));
+        }
+        let first = match self.iter_rows(ctx)? {
+            Ok(rows) => rows,
+            Err(err) => return Ok(Err(err)),
+        };
+        if let Err(err) = self
+            .fdw
+            .supabase_wrappers_routines()
+            .call_re_scan(&mut self.store, Resource::new_borrow(ctx))?
+        {
+            return Ok(Err(err));
+        }
+        let second = match self.iter_rows(ctx)? {
+            Ok(rows) => rows,
+            Err(err) => return Ok(Err(err)),
+        };
+        if let Err(err) = self
+            .fdw
+            .supabase_wrappers_routines()
+            .call_end_scan(&mut self.store, Resource::new_borrow(ctx))?
+        {
+            return Ok(Err(err));
+        }
+        Ok(Ok((first, second)))
+    }
+
+    // Call iter_scan until it reports no more rows
+    fn iter_rows(&mut self, ctx: u32) -> anyhow::Result<Result<Rows, String>> {
+        let routines = self.fdw.supabase_wrappers_routines();
+        let mut rows = Vec::new();
+        loop {
+            let row = self.store.data_mut().table.push(FakeRow::default())?;
+            let next = routines.call_iter_scan(&mut self.store, Resource::new_borrow(ctx), Resource::new_borrow(row.rep()))?;
+            let row = self.store.data_mut().table.delete(row)?;
+            match next {
+                Ok(Some(_)) => rows.push(row.cells),
+                Ok(None) => return Ok(Ok(rows)),
+                Err(err) => return Ok(Err(err)),
+            }
+        }
+    }
+
+    // Run begin_modify, the given row operations, and end_modify
+    pub fn modify(&mut self, query: Query, ops: Vec<Modify>) -> anyhow::Result<Result<(), String>> {
+        self.store.data_mut().query = query;
+        let ctx = self.store.data_mut().table.push(FakeContext)?;
+        let result = self.run_modify(ctx.rep(), ops);
+        self.store.data_mut().table.delete(ctx)?;
+        result
+    }
+
+    fn run_modify(&mut self, ctx: u32, ops: Vec<Modify>) -> anyhow::Result<Result<(), String>> {
+        let routines = self.fdw.supabase_wrappers_routines();
+        if let Err(err) = routines.call_begin_modify(&mut self.store, Resource::new_borrow(ctx))? {
+            return Ok(Err(err));
+        }
+
+        for op in ops {
+            let result = match op {
+                Modify::Insert(cells) => {
+                    let row = push_row(&mut self.store, cells)?;
+                    let result = routines.call_insert(&mut self.store, Resource::new_borrow(ctx), Resource::new_borrow(row.rep()));
+                    self.store.data_mut().table.delete(row)?;
+                    result?
+                }
+                Modify::Update(rowid, cells) => {
+                    let row = push_row(&mut self.store, cells)?;
+                    let result = routines.call_update(&mut self.store, Resource::new_borrow(ctx), &rowid, Resource::new_borrow(row.rep()));
+                    self.store.data_mut().table.delete(row)?;
+                    result?
+                }
+                Modify::Delete(rowid) => routines.call_delete(&mut self.store, Resource::new_borrow(ctx), &rowid)?,
+            };
+            if let Err(err) = result {
+                return Ok(Err(err));
+            }
+        }
+
+        routines.call_end_modify(&mut self.store, Resource::new_borrow(ctx))
+    }
+}
+
+// Row handed to insert/update, with the given column names and values
+fn push_row(store: &mut Store<FakeHost>, cells: Vec<(&str, Option<Cell>)>) -> anyhow::Result<Resource<FakeRow>> {
+    let row = FakeRow {
+        cols: cells.iter().map(|(col, _)| col.to_string()).collect(),
+        cells: cells.into_iter().map(|(_, cell)| cell).collect(),
+    };
+    Ok(store.data_mut().table.push(row)?)
+}
+
+// A row operation of a modify statement
+pub enum Modify<'a> {
+    Insert(Vec<(&'a str, Option<Cell>)>),
+    Update(Cell, Vec<(&'a str, Option<Cell>)>),
+    Delete(Cell),
+}
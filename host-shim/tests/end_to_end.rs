@@ -0,0 +1,516 @@
+// Copyright 2025 Automattic
+//
+// This file is part of Gravatar Wasm Foreign Data Wrapper which is licensed under
+// the GNU General Public License v3.0.
+
+// End-to-end tests of the built component. Build it first with
+// `cargo component build --release --target wasm32-unknown-unknown` in the
+// repository root, or point GRAVATAR_FDW_WASM at another build.
+
+use std::path::PathBuf;
+
+use gravatar_fdw_host_shim::{Cell, FakeHost, FakeQual, FakeResponse, Harness, Level, Query, TypeOid};
+
+const API_URL: &str = "https://api.gravatar.com/v3/profiles";
+const EMAIL: &str = "test@example.com";
+const HASH: &str = "973dfe463ec85785f5f95af5ba3906eedb2d931c24e69824a89ea65dba4e813b";
+// looked up by the health check and verify_credentials
+const HASH_OF_EMPTY_EMAIL: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+fn wasm_path() -> PathBuf {
+    let path = std::env::var("GRAVATAR_FDW_WASM").map(PathBuf::from).unwrap_or_else(|_| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../target/wasm32-unknown-unknown/release/gravatar_fdw.wasm")
+    });
+    assert!(
+        path.exists(),
+        "{} not found, build the component first or set GRAVATAR_FDW_WASM",
+        path.display()
+    );
+    path
+}
+
+fn harness(host: FakeHost) -> Harness {
+    let mut harness = Harness::new(wasm_path(), host).expect("instantiate component");
+    harness.init().expect("call init").expect("init succeeds");
+    harness
+}
+
+fn profiles_query() -> Query {
+    Query::table(
+        "profiles",
+        &[
+            ("hash", TypeOid::String),
+            ("display_name", TypeOid::String),
+            ("is_organization", TypeOid::Bool),
+            ("from_cache", TypeOid::Bool),
+        ],
+    )
+}
+
+fn profile_url(hash: &str) -> String {
+    format!("{}/{}", API_URL, hash)
+}
+
+#[test]
+fn declares_host_version_requirement() {
+    let mut harness = harness(FakeHost::default());
+    assert_eq!(harness.host_version_requirement().unwrap(), "^0.1.0");
+}
+
+#[test]
+fn scans_a_profile_by_email() {
+    let host = FakeHost::default().with_response(
+        &profile_url(HASH),
+        FakeResponse::json(200, r#"{"hash": "973dfe46", "display_name": "Test User", "is_organization": false}"#),
+    );
+    let mut harness = harness(host);
+
+    let rows = harness
+        .scan(profiles_query().with_qual(FakeQual::eq("email", EMAIL)))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![vec![
+            Some(Cell::String("973dfe46".to_owned())),
+            Some(Cell::String("Test User".to_owned())),
+            Some(Cell::Bool(false)),
+            Some(Cell::Bool(false)),
+        ]]
+    );
+    assert_eq!(harness.host().requests, vec![("GET".to_owned(), profile_url(HASH))]);
+}
+
+#[test]
+fn missing_profiles_return_no_rows() {
+    let mut harness = harness(FakeHost::default());
+
+    let rows = harness
+        .scan(profiles_query().with_qual(FakeQual::eq("email", EMAIL)))
+        .unwrap()
+        .unwrap();
+
+    assert!(rows.is_empty());
+    assert!(harness
+        .host()
+        .messages_at(Level::Info)
+        .iter()
+        .any(|msg| msg.contains("Profile not found")));
+}
+
+#[test]
+fn in_lists_look_up_each_email_once() {
+    let mut harness = harness(FakeHost::default());
+
+    harness
+        .scan(profiles_query().with_qual(FakeQual::any("email", &[EMAIL, "other@example.com", EMAIL])))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(harness.host().requests.len(), 2);
+}
+
+#[test]
+fn rows_follow_the_order_of_the_in_list() {
+    // sha256 of zed@example.com
+    let zed_hash = "e767f9ad378ffd1e179c9af19326070353b67764083fd552861660c8af41eb73";
+    let host = FakeHost::default()
+        .with_response(&profile_url(HASH), FakeResponse::json(200, r#"{"display_name": "Test"}"#))
+        .with_response(&profile_url(zed_hash), FakeResponse::json(200, r#"{"display_name": "Zed"}"#));
+    let mut harness = harness(host);
+
+    let rows = harness
+        .scan(
+            Query::table("profiles", &[("email", TypeOid::String)])
+                .with_qual(FakeQual::any("email", &["zed@example.com", EMAIL])),
+        )
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            vec![Some(Cell::String("zed@example.com".to_owned()))],
+            vec![Some(Cell::String(EMAIL.to_owned()))],
+        ]
+    );
+}
+
+#[test]
+fn queries_without_email_send_no_requests() {
+    let mut harness = harness(FakeHost::default());
+
+    let rows = harness.scan(profiles_query()).unwrap().unwrap();
+
+    assert!(rows.is_empty());
+    assert!(harness.host().requests.is_empty());
+}
+
+#[test]
+fn parameterized_emails_are_looked_up() {
+    let host = FakeHost::default().with_response(
+        &profile_url(HASH),
+        FakeResponse::json(200, r#"{"hash": "973dfe46", "display_name": "Test User"}"#),
+    );
+    let mut harness = harness(host);
+    let param = FakeQual {
+        param: Some(1),
+        ..FakeQual::eq("email", EMAIL)
+    };
+
+    let rows = harness.scan(profiles_query().with_qual(param)).unwrap().unwrap();
+
+    assert_eq!(rows.len(), 1);
+    assert_eq!(harness.host().requests.len(), 1);
+}
+
+#[test]
+fn unsupported_operators_are_rejected() {
+    let mut harness = harness(FakeHost::default());
+    let like = FakeQual {
+        operator: "~~".to_owned(),
+        ..FakeQual::eq("email", "%@example.com")
+    };
+
+    let err = harness.scan(profiles_query().with_qual(like)).unwrap().unwrap_err();
+
+    assert!(err.contains("Unsupported operator LIKE ('~~')"), "{}", err);
+}
+
+#[test]
+fn rate_limiting_is_reported() {
+    let host = FakeHost::default().with_response(
+        &profile_url(HASH),
+        FakeResponse::json(429, "{}").with_header("x-ratelimit-reset", "0"),
+    );
+    let mut harness = harness(host);
+
+    let err = harness
+        .scan(profiles_query().with_qual(FakeQual::eq("email", EMAIL)))
+        .unwrap()
+        .unwrap_err();
+
+    assert!(err.starts_with("Rate limit exceeded (429)."), "{}", err);
+}
+
+#[test]
+fn api_key_from_vault_is_sent_and_never_logged() {
+    let host = FakeHost::default()
+        .with_server_option("api_key_id", "secret-uuid")
+        .with_server_option("debug", "true")
+        .with_vault_secret("secret-uuid", "vault-api-key");
+    let mut harness = harness(host);
+
+    harness
+        .scan(profiles_query().with_qual(FakeQual::eq("email", EMAIL)))
+        .unwrap()
+        .unwrap();
+
+    assert!(harness.host().messages.iter().all(|(_, msg)| !msg.contains("vault-api-key")));
+}
+
+#[test]
+fn pii_safe_logging_hides_emails_of_secondary_tables() {
+    let body = format!(r#"{{"error": "no profile for {}"}}"#, EMAIL);
+    let host = FakeHost::default()
+        .with_server_option("pii_safe_logging", "true")
+        .with_response(&profile_url(HASH), FakeResponse::json(500, &body));
+    let mut harness = harness(host);
+
+    harness
+        .scan(
+            Query::table("links", &[("email", TypeOid::String), ("url", TypeOid::String)])
+                .with_qual(FakeQual::eq("email", EMAIL)),
+        )
+        .unwrap()
+        .unwrap();
+
+    let messages = &harness.host().messages;
+    assert!(messages.iter().any(|(_, msg)| msg.contains(&format!("Lookup failed for email {}", HASH))));
+    assert!(messages.iter().all(|(_, msg)| !msg.contains(EMAIL)));
+}
+
+#[test]
+fn rejected_batch_probes_cost_no_budget() {
+    let host = FakeHost::default()
+        .with_server_option("batch_size", "2")
+        .with_server_option("batch_protocol", "hashes_query")
+        .with_server_option("daily_request_budget", "1")
+        .with_response(&profile_url(HASH), FakeResponse::json(200, r#"{"display_name": "Test User"}"#));
+    let mut harness = harness(host);
+
+    let rows = harness
+        .scan(profiles_query().with_qual(FakeQual::eq("email", EMAIL)))
+        .unwrap()
+        .unwrap();
+    let stats = harness
+        .scan(Query::table("stats", &[("requests", TypeOid::I64), ("last_error", TypeOid::String)]))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(rows.len(), 1);
+    assert_eq!(
+        harness.host().requests,
+        vec![
+            ("GET".to_owned(), format!("{}?hashes={}", API_URL, HASH)),
+            ("GET".to_owned(), profile_url(HASH)),
+        ]
+    );
+    assert_eq!(stats, vec![vec![Some(Cell::I64(1)), None]]);
+}
+
+#[test]
+fn batches_need_a_named_protocol() {
+    let host = FakeHost::default().with_server_option("batch_size", "2");
+    let mut harness = Harness::new(wasm_path(), host).expect("instantiate component");
+
+    let err = harness.init().unwrap().unwrap_err();
+
+    assert!(err.contains("requires batch_protocol 'hashes_query'"), "{}", err);
+}
+
+#[test]
+fn missing_profiles_are_not_counted_as_fetched() {
+    let mut harness = harness(FakeHost::default());
+
+    harness
+        .scan(
+            Query::table("links", &[("email", TypeOid::String), ("url", TypeOid::String)])
+                .with_qual(FakeQual::eq("email", EMAIL)),
+        )
+        .unwrap()
+        .unwrap();
+    let stats = harness
+        .scan(Query::table("stats", &[("requests", TypeOid::I64), ("fetched", TypeOid::I64)]))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(stats, vec![vec![Some(Cell::I64(1)), Some(Cell::I64(0))]]);
+}
+
+#[test]
+fn mock_responses_short_circuit_http() {
+    let mocks = format!(r#"{{"{}": {{"display_name": "Mocked"}}}}"#, HASH);
+    let mut harness = harness(FakeHost::default().with_server_option("mock_responses", &mocks));
+
+    let rows = harness
+        .scan(profiles_query().with_qual(FakeQual::eq("email", EMAIL)))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(rows[0][1], Some(Cell::String("Mocked".to_owned())));
+    assert!(harness.host().requests.is_empty());
+}
+
+#[test]
+fn health_reports_failures_as_a_row() {
+    let host = FakeHost::default().with_response(&profile_url(HASH_OF_EMPTY_EMAIL), FakeResponse::json(503, "{}"));
+    let mut harness = harness(host);
+
+    let rows = harness
+        .scan(Query::table(
+            "health",
+            &[("status", TypeOid::String), ("status_code", TypeOid::I32), ("authenticated", TypeOid::Bool)],
+        ))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![vec![Some(Cell::String("error".to_owned())), Some(Cell::I32(503)), Some(Cell::Bool(false))]]
+    );
+}
+
+#[test]
+fn json_metrics_are_one_notice() {
+    let host = FakeHost::default()
+        .with_server_option("scan_metrics_format", "json")
+        .with_response(&profile_url(HASH), FakeResponse::json(200, r#"{"display_name": "Test User"}"#));
+    let mut harness = harness(host);
+
+    harness
+        .scan(profiles_query().with_qual(FakeQual::any("email", &[EMAIL, "other@example.com"])))
+        .unwrap()
+        .unwrap();
+
+    let notices = harness.host().messages_at(Level::Notice);
+    assert_eq!(notices.len(), 1);
+    assert!(notices[0].starts_with('{'));
+    assert!(notices[0].contains(r#""event":"gravatar_scan""#));
+    assert!(notices[0].contains(r#""fetched":1"#));
+    assert!(notices[0].contains(r#""requests":2"#));
+    assert!(harness
+        .host()
+        .messages_at(Level::Info)
+        .iter()
+        .all(|msg| !msg.contains("Gravatar scan finished")));
+}
+
+#[test]
+fn re_scan_returns_emitted_rows_again() {
+    let host = FakeHost::default().with_response(
+        &profile_url(HASH),
+        FakeResponse::json(200, r#"{"hash": "973dfe46", "display_name": "Test User", "is_organization": false}"#),
+    );
+    let mut harness = harness(host);
+
+    let (first, second) = harness
+        .scan_and_rescan(profiles_query().with_qual(FakeQual::eq("email", EMAIL)))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(first.len(), 1);
+    assert_eq!(second, first);
+    assert_eq!(harness.host().requests.len(), 1);
+}
+
+#[test]
+fn empty_strings_can_be_nulled() {
+    let host = FakeHost::default().with_response(
+        &profile_url(HASH),
+        FakeResponse::json(200, r#"{"hash": "973dfe46", "display_name": "", "is_organization": false}"#),
+    );
+    let mut harness = harness(host);
+    let query = profiles_query().with_qual(FakeQual::eq("email", EMAIL));
+
+    let rows = harness.scan(query.clone()).unwrap().unwrap();
+    assert_eq!(rows[0][1], Some(Cell::String(String::new())));
+
+    let rows = harness.scan(query.with_option("nullify_empty_strings", "true")).unwrap().unwrap();
+    assert_eq!(rows[0][1], None);
+}
+
+#[test]
+fn empty_json_fields_can_be_nulled() {
+    let host = FakeHost::default().with_response(
+        &profile_url(HASH),
+        FakeResponse::json(200, r#"{"links": [], "payments": {"links": [], "crypto_wallets": []}, "languages": ["en"]}"#),
+    );
+    let mut harness = harness(host);
+    let query = Query::table(
+        "profiles",
+        &[("links", TypeOid::Json), ("payments", TypeOid::Json), ("languages", TypeOid::Json)],
+    )
+    .with_qual(FakeQual::eq("email", EMAIL));
+
+    let rows = harness.scan(query.clone()).unwrap().unwrap();
+    assert_eq!(rows[0][0], Some(Cell::Json("[]".to_owned())));
+
+    let rows = harness.scan(query.with_option("nullify_empty_json", "true")).unwrap().unwrap();
+    assert_eq!(rows[0], vec![None, None, Some(Cell::Json(r#"["en"]"#.to_owned()))]);
+}
+
+#[test]
+fn md5_fallback_looks_up_legacy_hash_after_404() {
+    let md5_url = profile_url("55502f40dc8b7c769880b10874abc9d0");
+    let host = FakeHost::default()
+        .with_server_option("md5_fallback", "true")
+        .with_response(&md5_url, FakeResponse::json(200, r#"{"display_name": "Legacy User"}"#));
+    let mut harness = harness(host);
+
+    let rows = harness.scan(profiles_query().with_qual(FakeQual::eq("email", EMAIL))).unwrap().unwrap();
+    assert_eq!(rows[0][1], Some(Cell::String("Legacy User".to_owned())));
+    let urls: Vec<&str> = harness.host().requests.iter().map(|(_, url)| url.as_str()).collect();
+    assert_eq!(urls, vec![profile_url(HASH).as_str(), md5_url.as_str()]);
+}
+
+#[test]
+fn server_label_is_returned_on_every_row() {
+    let host = FakeHost::default()
+        .with_server_option("server_label", "eu-proxy")
+        .with_response(&profile_url(HASH), FakeResponse::json(200, r#"{"display_name": "Test User"}"#));
+    let mut harness = harness(host);
+    let query = Query::table("profiles", &[("display_name", TypeOid::String), ("server_label", TypeOid::String)])
+        .with_qual(FakeQual::eq("email", EMAIL));
+
+    let rows = harness.scan(query).unwrap().unwrap();
+    assert_eq!(rows[0][1], Some(Cell::String("eu-proxy".to_owned())));
+}
+
+#[test]
+fn free_form_text_can_be_sanitized() {
+    let host = FakeHost::default().with_response(
+        &profile_url(HASH),
+        FakeResponse::json(200, r#"{"display_name": "Tom &amp; Jerry\u0007", "location": "A&amp;B"}"#),
+    );
+    let mut harness = harness(host);
+    let query = Query::table("profiles", &[("display_name", TypeOid::String), ("location", TypeOid::String)])
+        .with_qual(FakeQual::eq("email", EMAIL))
+        .with_option("sanitize_text", "true");
+
+    let rows = harness.scan(query).unwrap().unwrap();
+    assert_eq!(rows[0][0], Some(Cell::String("Tom & Jerry".to_owned())));
+    assert_eq!(rows[0][1], Some(Cell::String("A&amp;B".to_owned())));
+}
+
+#[test]
+fn inferred_interests_are_only_fetched_when_selected() {
+    let inferred_url = format!("{}/inferred-interests", profile_url(HASH));
+    let host = FakeHost::default()
+        .with_response(&profile_url(HASH), FakeResponse::json(200, r#"{"display_name": "Test User"}"#))
+        .with_response(&inferred_url, FakeResponse::json(200, r#"[{"id": 1, "name": "hiking"}]"#));
+    let mut harness = harness(host);
+
+    harness.scan(profiles_query().with_qual(FakeQual::eq("email", EMAIL))).unwrap().unwrap();
+    assert_eq!(harness.host().requests.len(), 1);
+
+    let rows = harness
+        .scan(
+            Query::table("profiles", &[("display_name", TypeOid::String), ("inferred_interests", TypeOid::Json)])
+                .with_qual(FakeQual::eq("email", EMAIL)),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(rows[0][1], Some(Cell::Json(r#"[{"id":1,"name":"hiking"}]"#.to_owned())));
+    assert_eq!(harness.host().requests.last(), Some(&("GET".to_owned(), inferred_url)));
+}
+
+#[test]
+fn exists_only_checks_avatars_without_fetching_profiles() {
+    let avatar_url = format!("https://gravatar.com/avatar/{}?d=404&s=1", HASH);
+    let host = FakeHost::default()
+        .with_server_option("api_key", "secret-key")
+        .with_server_option("query_params", r#"{"team": "data"}"#)
+        .with_response(&avatar_url, FakeResponse::json(200, ""));
+    let mut harness = harness(host);
+
+    let rows = harness
+        .scan(
+            Query::table("profiles", &[("email", TypeOid::String), ("exists", TypeOid::Bool)])
+                .with_option("exists_only", "true")
+                .with_qual(FakeQual::any("email", &[EMAIL, "other@example.com"])),
+        )
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            vec![Some(Cell::String(EMAIL.to_owned())), Some(Cell::Bool(true))],
+            vec![Some(Cell::String("other@example.com".to_owned())), Some(Cell::Bool(false))],
+        ]
+    );
+    assert_eq!(harness.host().requests[0], ("GET".to_owned(), avatar_url));
+    assert!(harness.host().requests.iter().all(|(_, url)| url.contains("/avatar/") && !url.contains("team=")));
+    // the public avatar host never sees the API credential
+    for headers in &harness.host().request_headers {
+        assert!(headers.iter().all(|(name, value)| name != "authorization" && !value.contains("secret-key")));
+    }
+}
+
+#[test]
+fn query_params_are_added_to_every_request() {
+    let host = FakeHost::default().with_server_option("query_params", r#"{"team": "data eng"}"#);
+    let mut harness = harness(host);
+
+    harness
+        .scan(profiles_query().with_qual(FakeQual::eq("email", EMAIL)))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        harness.host().requests,
+        vec![("GET".to_owned(), format!("{}?team=data%20eng", profile_url(HASH)))]
+    );
+}
@@ -0,0 +1,122 @@
+// Copyright 2025 Automattic
+//
+// This file is part of Gravatar Wasm Foreign Data Wrapper which is licensed under
+// the GNU General Public License v3.0.
+
+// Native troubleshooting tool: looks up one email and prints every profile
+// column exactly as the FDW maps it. Built with `--features cli`.
+//
+//   cargo run --features cli --bin gravatar-lookup -- test@example.com [--api-key KEY] [--api-url URL]
+//
+// The API key can also come from the GRAVATAR_API_KEY environment variable.
+
+#[allow(dead_code)]
+#[path = "../fdw_core.rs"]
+mod fdw_core;
+
+use std::process::ExitCode;
+
+use fdw_core::{hash_email, profile_cell, Cell, TypeOid, JSON_FIELDS, TEXT_FIELDS};
+use serde_json::Value as JsonValue;
+
+const DEFAULT_API_URL: &str = "https://api.gravatar.com/v3/profiles";
+
+struct Args {
+    email: String,
+    api_key: Option<String>,
+    api_url: String,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut email = None;
+    let mut api_key = std::env::var("GRAVATAR_API_KEY").ok().filter(|key| !key.is_empty());
+    let mut api_url = DEFAULT_API_URL.to_owned();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--api-key" => api_key = Some(args.next().ok_or("--api-key needs a value")?),
+            "--api-url" => api_url = args.next().ok_or("--api-url needs a value")?,
+            _ if arg.starts_with("--") => return Err(format!("Unknown option {}", arg)),
+            _ if email.is_none() => email = Some(arg),
+            _ => return Err("Only one email can be looked up at a time".to_owned()),
+        }
+    }
+
+    Ok(Args {
+        email: email.ok_or("Usage: gravatar-lookup <email> [--api-key KEY] [--api-url URL]")?,
+        api_key,
+        api_url,
+    })
+}
+
+fn fetch_profile(args: &Args) -> Result<JsonValue, String> {
+    let url = format!("{}/{}", args.api_url, hash_email(&args.email));
+    let user_agent = format!("{}-lookup/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+    let mut req = ureq::get(&url).set("user-agent", &user_agent).set("accept", "application/json");
+    if let Some(api_key) = &args.api_key {
+        req = req.set("authorization", &format!("Bearer {}", api_key));
+    }
+
+    eprintln!("GET {}", url);
+    let resp = match req.call() {
+        Ok(resp) => resp,
+        Err(ureq::Error::Status(status, resp)) => {
+            let body = resp.into_string().unwrap_or_default();
+            return Err(format!("HTTP error {}: {}", status, body));
+        }
+        Err(err) => return Err(format!("Request failed: {}", err)),
+    };
+    for name in ["x-ratelimit-limit", "x-ratelimit-remaining", "x-ratelimit-reset"] {
+        if let Some(value) = resp.header(name) {
+            eprintln!("{}: {}", name, value);
+        }
+    }
+
+    let body = resp.into_string().map_err(|e| format!("Failed to read response: {}", e))?;
+    let mut profile: JsonValue =
+        serde_json::from_str(&body).map_err(|e| format!("Failed to parse JSON response: {}", e))?;
+    // the FDW adds the email, which the API doesn't return
+    if let JsonValue::Object(ref mut map) = profile {
+        map.insert("email".to_string(), JsonValue::String(args.email.clone()));
+    }
+    Ok(profile)
+}
+
+fn format_cell(cell: Option<Cell>) -> String {
+    match cell {
+        None => "NULL".to_owned(),
+        Some(Cell::String(s)) | Some(Cell::Json(s)) => s,
+        Some(Cell::Bool(b)) => b.to_string(),
+        Some(Cell::I64(i)) => i.to_string(),
+        Some(Cell::I32(i)) => i.to_string(),
+        Some(_) => "<unsupported>".to_owned(),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::from(2);
+        }
+    };
+    let profile = match fetch_profile(&args) {
+        Ok(profile) => profile,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let columns = TEXT_FIELDS
+        .iter()
+        .map(|name| (*name, TypeOid::String))
+        .chain([("is_organization", TypeOid::Bool), ("number_verified_accounts", TypeOid::I64)])
+        .chain(JSON_FIELDS.iter().map(|name| (*name, TypeOid::Json)));
+    for (name, type_oid) in columns {
+        println!("{:<26} {}", name, format_cell(profile_cell(&profile, name, type_oid)));
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,2446 @@
+// Copyright 2025 Automattic
+//
+// This file is part of Gravatar Wasm Foreign Data Wrapper which is licensed under
+// the GNU General Public License v3.0.
+
+// Logic that doesn't need the Wasm host: hashing, header and rate limit
+// parsing, qual extraction and profile to cell mapping. Host calls go through
+// small traits and values through the plain types below, so everything here
+// runs in native unit tests without the generated bindings.
+
+use hmac::{Hmac, Mac};
+use md5::Md5;
+use serde_json::Value as JsonValue;
+use sha2::{Digest, Sha256};
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashSet};
+use unicode_normalization::UnicodeNormalization;
+
+pub(crate) type FdwError = String;
+
+// Mirrors of the WIT `cell`, `type-oid` and `value` types, converted from and
+// to the bindings at the host boundary in lib.rs
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Cell {
+    Bool(bool),
+    I8(i8),
+    I16(i16),
+    F32(f32),
+    I32(i32),
+    F64(f64),
+    I64(i64),
+    Numeric(f64),
+    String(String),
+    // seconds since Unix epoch
+    Date(i64),
+    // microseconds since Unix epoch
+    Timestamp(i64),
+    Timestamptz(i64),
+    Json(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum TypeOid {
+    Bool,
+    I8,
+    I16,
+    F32,
+    I32,
+    F64,
+    I64,
+    Numeric,
+    String,
+    Date,
+    Timestamp,
+    Timestamptz,
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Cell(Cell),
+    Array(Vec<Cell>),
+}
+
+// Host functions the core logic depends on
+pub(crate) trait Host {
+    fn vault_secret(&self, secret_id: &str) -> Option<String>;
+}
+
+// Read access to FDW options
+pub(crate) trait OptionSource {
+    fn get_option(&self, key: &str) -> Option<String>;
+}
+
+// A query condition, as far as qual extraction is concerned
+pub(crate) trait QualLike {
+    fn field(&self) -> String;
+    fn operator(&self) -> String;
+    fn use_or(&self) -> bool;
+    fn value(&self) -> Value;
+    // ID of the query parameter the value comes from, e.g. $1 of a prepared
+    // statement or the outer row of a nested loop join
+    fn param_id(&self) -> Option<u32>;
+}
+
+// Hash email using SHA-256
+pub(crate) fn hash_email(email: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(email.trim().to_lowercase().as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+// Hash email using MD5, the legacy Gravatar hash some old accounts only
+// resolve by
+pub(crate) fn hash_email_md5(email: &str) -> String {
+    format!("{:x}", Md5::digest(email.trim().to_lowercase().as_bytes()))
+}
+
+// Basic RFC 5321/5322 shape of an email, checked before hashing so junk
+// values don't cost API requests: a dot-atom local part of at most 64
+// characters, and a domain of at least two labels made of letters, digits
+// and hyphens. Non-ASCII characters are allowed for internationalized emails.
+pub(crate) fn valid_email_syntax(email: &str) -> bool {
+    const LOCAL_SPECIALS: &str = "!#$%&'*+-/=?^_`{|}~";
+    let email = email.trim();
+    let Some((local, domain)) = email.rsplit_once('@') else {
+        return false;
+    };
+    let local_ok = !local.is_empty()
+        && local.len() <= 64
+        && local
+            .split('.')
+            .all(|atom| !atom.is_empty() && atom.chars().all(|c| c.is_alphanumeric() || LOCAL_SPECIALS.contains(c)));
+    let labels: Vec<&str> = domain.split('.').collect();
+    let domain_ok = labels.len() >= 2
+        && domain.len() <= 253
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        });
+    local_ok && domain_ok && email.len() <= 254
+}
+
+// Treatment of internationalized emails before hashing
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) enum IdnPolicy {
+    // hash the email as given
+    #[default]
+    Preserve,
+    // Unicode NFC, so composed and decomposed accents hash alike
+    Nfc,
+    // NFC, and non-ASCII domain labels in punycode (xn--...)
+    Punycode,
+}
+
+impl IdnPolicy {
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        match value {
+            "preserve" => Some(Self::Preserve),
+            "nfc" => Some(Self::Nfc),
+            "punycode" => Some(Self::Punycode),
+            _ => None,
+        }
+    }
+}
+
+// How emails are normalized before hashing, beyond the trimming and
+// lowercasing Gravatar itself expects
+#[derive(Debug, Default)]
+pub(crate) struct EmailNormalization {
+    // drop a `+tag` suffix from the local part, user+news@ -> user@
+    pub(crate) strip_plus_tags: bool,
+    pub(crate) idn: IdnPolicy,
+}
+
+impl EmailNormalization {
+    pub(crate) fn normalize(&self, email: &str) -> String {
+        let mut email = email.trim().to_lowercase();
+        if self.idn != IdnPolicy::Preserve && !email.is_ascii() {
+            email = email.nfc().collect();
+        }
+        let Some((local, domain)) = email.rsplit_once('@') else {
+            return email;
+        };
+        let local = match local.split_once('+') {
+            Some((base, _)) if self.strip_plus_tags && !base.is_empty() => base,
+            _ => local,
+        };
+        if self.idn == IdnPolicy::Punycode && !domain.is_ascii() {
+            return format!("{}@{}", local, domain_to_ascii(domain));
+        }
+        format!("{}@{}", local, domain)
+    }
+
+    pub(crate) fn hash(&self, email: &str) -> String {
+        hash_email(&self.normalize(email))
+    }
+
+    pub(crate) fn md5_hash(&self, email: &str) -> String {
+        hash_email_md5(&self.normalize(email))
+    }
+}
+
+// Domain in its ASCII form after UTS #46 mapping and validation, with the
+// hostname rules and DNS length limits that apply to email domains, e.g.
+// München.de -> xn--mnchen-3ya.de. Domains IDNA rejects are kept as they are.
+fn domain_to_ascii(domain: &str) -> String {
+    idna::domain_to_ascii_strict(domain).unwrap_or_else(|_| domain.to_owned())
+}
+
+// HMAC-SHA256 (RFC 2104) of the message, hex encoded
+pub(crate) fn hmac_sha256_hex(key: &[u8], message: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message);
+    format!("{:x}", mac.finalize().into_bytes())
+}
+
+// Parameters of the `query_params` option: a JSON object of string, number
+// or boolean values
+pub(crate) fn parse_query_params(value: &str) -> Option<Vec<(String, String)>> {
+    let JsonValue::Object(params) = serde_json::from_str(value).ok()? else {
+        return None;
+    };
+    params
+        .into_iter()
+        .map(|(name, value)| match value {
+            JsonValue::String(value) => Some((name, value)),
+            JsonValue::Number(_) | JsonValue::Bool(_) => Some((name, value.to_string())),
+            _ => None,
+        })
+        .collect()
+}
+
+// URL with the parameters appended to its query string, percent-encoded
+pub(crate) fn append_query_params(url: &str, params: &[(String, String)]) -> String {
+    let mut url = url.to_owned();
+    for (name, value) in params {
+        url.push(if url.contains('?') { '&' } else { '?' });
+        url.push_str(&format!("{}={}", form_encode(name), form_encode(value)));
+    }
+    url
+}
+
+// Percent-encode a value for an application/x-www-form-urlencoded body
+pub(crate) fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+// Case-insensitive lookup of a header value
+pub(crate) fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+// Numeric header value, e.g. rate limit counters
+pub(crate) fn header_i64(headers: &[(String, String)], name: &str) -> Option<i64> {
+    find_header(headers, name).and_then(|value| value.trim().parse().ok())
+}
+
+// Key of a URL in recorded and mock responses: its last path segment, i.e.
+// the hash for profile lookups
+pub(crate) fn response_key(url: &str) -> &str {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    path.rsplit('/').next().unwrap_or_default()
+}
+
+// Rate limit state reported by the API for one kind of request
+#[derive(Debug, Default)]
+pub(crate) struct RateLimitWindow {
+    // reset time of the window once the remaining quota hit zero
+    pub(crate) reset_at: Option<i64>,
+}
+
+impl RateLimitWindow {
+    // Remember when the quota resets once the API reports none left
+    pub(crate) fn track(&mut self, headers: &[(String, String)]) {
+        self.reset_at = match header_i64(headers, "x-ratelimit-remaining") {
+            Some(0) => header_i64(headers, "x-ratelimit-reset"),
+            _ => None,
+        };
+    }
+}
+
+// Live requests sent during the current UTC day, for daily_request_budget
+#[derive(Debug, Default, Clone)]
+pub(crate) struct DailyRequests {
+    // days since the epoch the count belongs to
+    day: i64,
+    count: u64,
+}
+
+impl DailyRequests {
+    const SECS_PER_DAY: i64 = 86400;
+
+    // Requests counted today; the count starts over at midnight UTC
+    pub(crate) fn today(&self, now: i64) -> u64 {
+        if now.div_euclid(Self::SECS_PER_DAY) == self.day {
+            self.count
+        } else {
+            0
+        }
+    }
+
+    pub(crate) fn record(&mut self, now: i64) {
+        self.count = self.today(now) + 1;
+        self.day = now.div_euclid(Self::SECS_PER_DAY);
+    }
+
+    // Seconds until the next midnight UTC
+    pub(crate) fn resets_in(now: i64) -> i64 {
+        Self::SECS_PER_DAY - now.rem_euclid(Self::SECS_PER_DAY)
+    }
+}
+
+// Gravatar avatar ratings, from the most to the least family-safe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum AvatarRating {
+    G,
+    Pg,
+    R,
+    X,
+}
+
+impl AvatarRating {
+    // Case-insensitive, the API reports ratings in upper case
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "g" => Some(Self::G),
+            "pg" => Some(Self::Pg),
+            "r" => Some(Self::R),
+            "x" => Some(Self::X),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::G => "g",
+            Self::Pg => "pg",
+            Self::R => "r",
+            Self::X => "x",
+        }
+    }
+
+    // Avatar URL asking Gravatar for an image no more explicit than this
+    // rating (the default image otherwise), replacing any rating parameter
+    pub(crate) fn avatar_url(self, url: &str) -> String {
+        let (base, query) = url.split_once('?').unwrap_or((url, ""));
+        let mut params: Vec<&str> = query
+            .split('&')
+            .filter(|param| !param.is_empty() && !param.starts_with("r=") && !param.starts_with("rating="))
+            .collect();
+        let rating = format!("r={}", self.as_str());
+        params.push(&rating);
+        format!("{}?{}", base, params.join("&"))
+    }
+}
+
+// Gravatar image URL served through `cdn_base` instead, keeping the path
+// (hash) and query (size, default image, rating). URLs on other hosts are
+// returned unchanged.
+pub(crate) fn cdn_avatar_url(url: &str, cdn_base: &str) -> String {
+    let Some((_, rest)) = url.split_once("://") else {
+        return url.to_owned();
+    };
+    let (host, path) = rest.find(['/', '?']).map_or((rest, ""), |idx| rest.split_at(idx));
+    let host = host.to_lowercase();
+    if host != "gravatar.com" && !host.ends_with(".gravatar.com") {
+        return url.to_owned();
+    }
+    format!("{}{}", cdn_base.trim_end_matches('/'), path)
+}
+
+// Base of the avatar image URLs checked by `exists_only`. The production API's
+// avatars are served by gravatar.com; staging and proxies set with `api_url`
+// are expected to serve /avatar next to /v3/profiles.
+pub(crate) fn avatar_base_url(api_base_url: &str) -> String {
+    if api_base_url == Environment::Production.base_url() {
+        return "https://gravatar.com/avatar".to_owned();
+    }
+    let root = api_base_url.trim_end_matches('/');
+    let root = root.strip_suffix("/profiles").unwrap_or(root);
+    let root = root.strip_suffix("/v3").unwrap_or(root);
+    format!("{}/avatar", root)
+}
+
+// Requests sent with one credential against one quota, and the quota as
+// the API last reported it in its rate limit headers
+#[derive(Debug, Default, Clone)]
+pub(crate) struct KeyUsage {
+    pub(crate) requests: u64,
+    pub(crate) limit: Option<i64>,
+    pub(crate) remaining: Option<i64>,
+    pub(crate) reset_at: Option<i64>,
+}
+
+impl KeyUsage {
+    // Count a request; responses without rate limit headers keep the last known quota
+    pub(crate) fn record(&mut self, headers: Option<&[(String, String)]>) {
+        self.requests += 1;
+        let Some(headers) = headers else {
+            return;
+        };
+        if let Some(limit) = header_i64(headers, "x-ratelimit-limit") {
+            self.limit = Some(limit);
+        }
+        if let Some(remaining) = header_i64(headers, "x-ratelimit-remaining") {
+            self.remaining = Some(remaining);
+        }
+        if let Some(reset_at) = header_i64(headers, "x-ratelimit-reset") {
+            self.reset_at = Some(reset_at);
+        }
+    }
+
+    // Requests used in the current window, all connections together
+    pub(crate) fn used(&self) -> Option<i64> {
+        Some(self.limit? - self.remaining?)
+    }
+}
+
+// How often and how patiently failed requests are retried
+#[derive(Debug, Default)]
+pub(crate) struct RetryPolicy {
+    pub(crate) max_retries: u32,
+    // delay before the first retry, doubled for every further attempt
+    pub(crate) backoff_ms: u64,
+    // explicit delays from retry_schedule, replacing the exponential backoff;
+    // attempts past its end reuse the last delay
+    pub(crate) schedule: Vec<u64>,
+}
+
+impl RetryPolicy {
+    pub(crate) fn delay_ms(&self, attempt: u32) -> u64 {
+        if let Some(last) = self.schedule.last() {
+            return self.schedule.get(attempt as usize).copied().unwrap_or(*last);
+        }
+        self.backoff_ms.saturating_mul(1u64 << attempt.min(16))
+    }
+
+    // Rate limiting and server errors are worth another try
+    pub(crate) fn should_retry(&self, attempt: u32, status_code: u16) -> bool {
+        attempt < self.max_retries && (status_code == 429 || status_code >= 500)
+    }
+}
+
+// Parse a retry_schedule value like `1s,5s,30s` into delays in milliseconds.
+// Each delay takes an `ms`, `s` or `m` unit; bare numbers are milliseconds.
+pub(crate) fn parse_retry_schedule(value: &str) -> Option<Vec<u64>> {
+    let delays = value
+        .split(',')
+        .map(|delay| {
+            let delay = delay.trim();
+            let (number, factor) = if let Some(number) = delay.strip_suffix("ms") {
+                (number, 1)
+            } else if let Some(number) = delay.strip_suffix('s') {
+                (number, 1000)
+            } else if let Some(number) = delay.strip_suffix('m') {
+                (number, 60_000)
+            } else {
+                (delay, 1)
+            };
+            number.trim().parse::<u64>().ok()?.checked_mul(factor)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(delays)
+}
+
+// Deployment preset selected by the `environment` option. Explicit options
+// (api_url, write_max_retries, ...) override the preset values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Environment {
+    Production,
+    Staging,
+}
+
+impl Environment {
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        match value {
+            "production" => Some(Self::Production),
+            "staging" => Some(Self::Staging),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn base_url(self) -> &'static str {
+        match self {
+            Self::Production => "https://api.gravatar.com/v3/profiles",
+            Self::Staging => "https://staging-api.gravatar.com/v3/profiles",
+        }
+    }
+
+    // Longest pause, in seconds, while waiting for an exhausted rate limit
+    // to reset. Integration runs would rather wait than fail.
+    pub(crate) fn max_rate_limit_wait_secs(self) -> i64 {
+        match self {
+            Self::Production => 60,
+            Self::Staging => 300,
+        }
+    }
+
+    // Write retries when write_max_retries and write_retry_backoff_ms are not set
+    pub(crate) fn write_retry(self) -> RetryPolicy {
+        match self {
+            Self::Production => RetryPolicy {
+                max_retries: 0,
+                backoff_ms: 1000,
+                schedule: Vec::new(),
+            },
+            Self::Staging => RetryPolicy {
+                max_retries: 3,
+                backoff_ms: 500,
+                schedule: Vec::new(),
+            },
+        }
+    }
+}
+
+// What a failed lookup does to the scan
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) enum ErrorAction {
+    // abort the query with the error
+    #[default]
+    Fail,
+    // warn and leave the email out
+    Skip,
+    // warn and return a row with only email, hash and the error column
+    Row,
+}
+
+impl ErrorAction {
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        match value {
+            "error" => Some(Self::Fail),
+            "skip" => Some(Self::Skip),
+            "row" => Some(Self::Row),
+            _ => None,
+        }
+    }
+}
+
+// Where the value of a credential option comes from. Every credential can
+// be given directly as `<name>` or as a Vault secret UUID in `<name>_id`;
+// new sources are added here. No Debug derive, a direct value is the secret
+// itself.
+#[derive(Clone)]
+pub(crate) enum SecretSource {
+    Direct(String),
+    VaultId(String),
+}
+
+impl SecretSource {
+    // The source configured for the credential `name`, if any. Vault secret
+    // names (`<name>_name`) are rejected: get-vault-secret of the 0.1 host
+    // only takes secret IDs.
+    pub(crate) fn from_options(opts: &impl OptionSource, name: &str) -> Result<Option<Self>, FdwError> {
+        if let Some(value) = opts.get_option(name) {
+            return Ok(Some(SecretSource::Direct(value)));
+        }
+        if let Some(id) = opts.get_option(&format!("{}_id", name)) {
+            return Ok(Some(SecretSource::VaultId(id)));
+        }
+        if opts.get_option(&format!("{}_name", name)).is_some() {
+            return Err(format!(
+                "Option '{}_name' is not supported by the Wrappers 0.1 host, which only reads Vault secrets by ID. \
+                 Use '{}_id' with the secret's UUID instead.",
+                name, name
+            ));
+        }
+        Ok(None)
+    }
+
+    // Whether the value can change behind our back, e.g. after a rotation
+    pub(crate) fn is_vault(&self) -> bool {
+        !matches!(self, SecretSource::Direct(_))
+    }
+
+    pub(crate) fn resolve(&self, name: &str, host: &impl Host) -> Result<String, FdwError> {
+        let (value, location) = match self {
+            SecretSource::Direct(value) => return Ok(value.clone()),
+            SecretSource::VaultId(id) => (host.vault_secret(id), format!("ID: {}", id)),
+        };
+        match value {
+            Some(value) if !value.is_empty() => Ok(value),
+            _ => Err(format!("Failed to retrieve {} from Vault using {}", name, location)),
+        }
+    }
+}
+
+// Whether a qual compares the email column, possibly wrapped in functions
+// that hashing applies anyway: lower(email), trim(email), lower(trim(email))
+fn is_email_field(field: &str) -> bool {
+    let mut field = field.trim();
+    loop {
+        let inner = ["lower(", "btrim(", "trim("]
+            .iter()
+            .find_map(|wrapper| field.strip_prefix(wrapper))
+            .and_then(|rest| rest.strip_suffix(')'));
+        match inner {
+            Some(inner) => field = inner.trim(),
+            None => return field == "email" || field == "\"email\"",
+        }
+    }
+}
+
+// Emails requested by `email = ...` or `email IN (...)`, each listed once.
+// Several separate email conditions and other operators are rejected.
+pub(crate) fn extract_emails<Q: QualLike>(quals: &[Q]) -> Result<Vec<String>, FdwError> {
+    let mut emails = Vec::new();
+    let mut email_quals = 0;
+
+    for qual in quals.iter().filter(|qual| is_email_field(&qual.field())) {
+        if qual.operator() != "=" {
+            return Err(unsupported_email_operator(qual));
+        }
+        email_quals += 1;
+        match qual.value() {
+            Value::Cell(Cell::String(email)) => emails.push(email),
+            // email IN (...) arrives as a single OR-ed qual holding an array
+            Value::Array(cells) if qual.use_or() => {
+                emails.extend(cells.into_iter().filter_map(|cell| match cell {
+                    Cell::String(email) => Some(email),
+                    _ => None,
+                }));
+            }
+            // The host evaluates parameters before the scan starts. Anything
+            // but text means it couldn't, and ignoring the condition would
+            // look like a query without email filter.
+            value => {
+                if let Some(id) = qual.param_id() {
+                    return Err(format!(
+                        "The email condition uses query parameter ${} but its value ({:?}) is not text. \
+                         Compare email with a text value, e.g. email = $1::text.",
+                        id, value
+                    ));
+                }
+            }
+        }
+    }
+
+    // Only allow one email condition; several emails go in an IN list
+    if !emails.is_empty() && email_quals > 1 {
+        return Err(format!("Multiple email filters are not supported. Found {} email conditions. Use email IN (...) to look up several emails.", email_quals));
+    }
+
+    let mut seen = HashSet::new();
+    emails.retain(|email| seen.insert(email.clone()));
+    Ok(emails)
+}
+
+// Error for an email qual that can't be pushed down, naming the operator as
+// written in SQL rather than Postgres' internal name (LIKE arrives as '~~')
+fn unsupported_email_operator<Q: QualLike>(qual: &Q) -> FdwError {
+    let operator = qual.operator();
+    let in_list = matches!(qual.value(), Value::Array(_));
+    let sql = match operator.as_str() {
+        "<>" if in_list => "NOT IN",
+        "<>" => "!=",
+        "~~" => "LIKE",
+        "~~*" => "ILIKE",
+        "!~~" => "NOT LIKE",
+        "!~~*" => "NOT ILIKE",
+        "~" | "~*" | "!~" | "!~*" => "regular expression match",
+        "<" | "<=" | ">" | ">=" => "range comparison",
+        _ => "",
+    };
+    let operator = if sql.is_empty() {
+        format!("'{}'", operator)
+    } else {
+        format!("{} ('{}')", sql, operator)
+    };
+    let hint = match sql {
+        "LIKE" | "ILIKE" | "NOT LIKE" | "NOT ILIKE" | "regular expression match" => {
+            " To filter by pattern, select the matching emails from a local table and look them up with IN."
+        }
+        _ => "",
+    };
+    format!(
+        "Unsupported operator {} on the email column. Gravatar looks profiles up by exact email hash, so only \
+         email = '...' and email IN (...) can be pushed down.{}",
+        operator, hint
+    )
+}
+
+// A qual as written in SQL, e.g. `email IN` or `url LIKE`, for debug logs
+pub(crate) fn describe_qual<Q: QualLike>(qual: &Q) -> String {
+    let operator = qual.operator();
+    let in_list = matches!(qual.value(), Value::Array(_));
+    let sql = match operator.as_str() {
+        "=" if in_list => "IN",
+        "<>" if in_list => "NOT IN",
+        "~~" => "LIKE",
+        "~~*" => "ILIKE",
+        "!~~" => "NOT LIKE",
+        "!~~*" => "NOT ILIKE",
+        other => other,
+    };
+    format!("{} {}", qual.field(), sql)
+}
+
+// Whether the scan of `table` acts on the qual, i.e. it decides which
+// lookups are made or which rows are built. Other quals are only evaluated
+// by Postgres on the returned rows.
+pub(crate) fn qual_pushed_down<Q: QualLike>(table: &str, qual: &Q) -> bool {
+    const PROFILE_TABLES: &[&str] = &["profiles", "profiles_public", "profiles_full"];
+    const EMAIL_TABLES: &[&str] = &["profiles_raw", "qr", "all_interests", "links"];
+    const TEXT_OPERATORS: &[&str] = &["=", "~~", "~~*"];
+    let field = qual.field();
+    let operator = qual.operator();
+    let profile_table = PROFILE_TABLES.contains(&table);
+    let operators: &[&str] = match field.as_str() {
+        "api_key_id" => &["="],
+        _ if is_email_field(&field) && (profile_table || EMAIL_TABLES.contains(&table)) => &["="],
+        "emails" | "max_age" if profile_table => &["="],
+        "size" | "version" | "type" if table == "qr" => &["="],
+        "label" | "url" if table == "links" => TEXT_OPERATORS,
+        "source" if table == "all_interests" => TEXT_OPERATORS,
+        _ => &[],
+    };
+    operators.contains(&operator.as_str())
+}
+
+// Value of an `emails = 'a@example.com;b@example.com'` pseudo-qual, for
+// clients that can't generate IN lists, and the emails it lists. They are
+// separated by semicolons or commas.
+pub(crate) fn extract_email_list<Q: QualLike>(quals: &[Q]) -> Result<Option<(String, Vec<String>)>, FdwError> {
+    let Some(qual) = quals.iter().find(|qual| qual.field() == "emails") else {
+        return Ok(None);
+    };
+    let list = match (qual.operator().as_str(), qual.value()) {
+        ("=", Value::Cell(Cell::String(list))) => list,
+        _ => return Err("Invalid emails condition. Use emails = 'a@example.com;b@example.com'.".to_owned()),
+    };
+    let mut seen = HashSet::new();
+    let emails = list
+        .split([';', ','])
+        .map(str::trim)
+        .filter(|email| !email.is_empty() && seen.insert(*email))
+        .map(str::to_owned)
+        .collect();
+    Ok(Some((list, emails)))
+}
+
+// Value of a `max_age = <seconds>` pseudo-qual, which forces refetching
+// cached profiles older than that
+pub(crate) fn extract_max_age<Q: QualLike>(quals: &[Q]) -> Result<Option<i64>, FdwError> {
+    let Some(qual) = quals.iter().find(|qual| qual.field() == "max_age") else {
+        return Ok(None);
+    };
+    let max_age = match (qual.operator().as_str(), qual.value()) {
+        ("=", Value::Cell(Cell::I64(secs))) => Some(secs),
+        ("=", Value::Cell(Cell::I32(secs))) => Some(secs.into()),
+        ("=", Value::Cell(Cell::I16(secs))) => Some(secs.into()),
+        _ => None,
+    };
+    match max_age {
+        Some(secs) if secs >= 0 => Ok(Some(secs)),
+        _ => Err("Invalid max_age condition. Use max_age = <seconds> with a non-negative integer.".to_owned()),
+    }
+}
+
+// One ORDER BY key pushed down by the host
+pub(crate) struct SortKey {
+    pub(crate) field: String,
+    pub(crate) reversed: bool,
+    pub(crate) nulls_first: bool,
+}
+
+// Order of two profiles under the ORDER BY keys. Missing fields and JSON
+// nulls are NULL; text compares by code point (like the "C" collation).
+pub(crate) fn compare_profiles(keys: &[SortKey], a: &JsonValue, b: &JsonValue) -> Ordering {
+    let value = |profile: &JsonValue, field: &str| profile.get(field).filter(|v| !v.is_null()).cloned();
+    for key in keys {
+        let ordering = match (value(a, &key.field), value(b, &key.field)) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) if key.nulls_first => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) if key.nulls_first => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (Some(a), Some(b)) => {
+                let ordering = match (&a, &b) {
+                    (JsonValue::Number(a), JsonValue::Number(b)) => a
+                        .as_f64()
+                        .partial_cmp(&b.as_f64())
+                        .unwrap_or(Ordering::Equal),
+                    (JsonValue::Bool(a), JsonValue::Bool(b)) => a.cmp(b),
+                    (JsonValue::String(a), JsonValue::String(b)) => a.cmp(b),
+                    _ => a.to_string().cmp(&b.to_string()),
+                };
+                if key.reversed {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+// Field a foreign table column maps to. Column names are matched
+// case-insensitively, and quoted camelCase names ("displayName") map to
+// snake_case fields, so such definitions don't silently come back NULL.
+pub(crate) fn column_field(name: &str) -> String {
+    let mut field = String::with_capacity(name.len() + 4);
+    let mut prev: Option<char> = None;
+    for c in name.chars() {
+        if c.is_uppercase() && prev.is_some_and(|prev| prev.is_lowercase() || prev.is_ascii_digit()) {
+            field.push('_');
+        }
+        field.extend(c.to_lowercase());
+        prev = Some(c);
+    }
+    field
+}
+
+// Value of a `<field> = <constant>` qual on a pseudo-column such as the QR
+// code size, if any
+pub(crate) fn pseudo_qual<Q: QualLike>(quals: &[Q], field: &str) -> Option<Cell> {
+    quals
+        .iter()
+        .filter(|qual| qual.field() == field && qual.operator() == "=")
+        .find_map(|qual| match qual.value() {
+            Value::Cell(cell) => Some(cell),
+            Value::Array(_) => None,
+        })
+}
+
+// A condition on a text column applied by the FDW before building rows:
+// `=` or IN, LIKE ('~~') and ILIKE ('~~*'). Postgres rechecks every qual,
+// so conditions not understood here are left to it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TextFilter {
+    field: String,
+    matcher: TextMatch,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TextMatch {
+    OneOf(Vec<String>),
+    Like(String),
+    ILike(String),
+}
+
+impl TextFilter {
+    // Filters for the quals on `fields`
+    pub(crate) fn from_quals<Q: QualLike>(quals: &[Q], fields: &[&str]) -> Vec<TextFilter> {
+        quals
+            .iter()
+            .filter(|qual| fields.contains(&qual.field().as_str()))
+            .filter_map(|qual| {
+                let matcher = match (qual.operator().as_str(), qual.value()) {
+                    ("=", Value::Cell(Cell::String(value))) => TextMatch::OneOf(vec![value]),
+                    ("=", Value::Array(cells)) if qual.use_or() => TextMatch::OneOf(
+                        cells
+                            .into_iter()
+                            .filter_map(|cell| match cell {
+                                Cell::String(value) => Some(value),
+                                _ => None,
+                            })
+                            .collect(),
+                    ),
+                    ("~~", Value::Cell(Cell::String(pattern))) => TextMatch::Like(pattern),
+                    ("~~*", Value::Cell(Cell::String(pattern))) => TextMatch::ILike(pattern.to_lowercase()),
+                    _ => return None,
+                };
+                Some(TextFilter {
+                    field: qual.field(),
+                    matcher,
+                })
+            })
+            .collect()
+    }
+
+    // Whether the row's field passes the condition; a missing field never does
+    pub(crate) fn matches(&self, row: &JsonValue) -> bool {
+        let Some(value) = row.get(&self.field).and_then(|v| v.as_str()) else {
+            return false;
+        };
+        match &self.matcher {
+            TextMatch::OneOf(values) => values.iter().any(|candidate| candidate == value),
+            TextMatch::Like(pattern) => like_match(pattern, value),
+            TextMatch::ILike(pattern) => like_match(pattern, &value.to_lowercase()),
+        }
+    }
+}
+
+// SQL LIKE: `%` matches any run of characters, `_` one character and a
+// backslash escapes the next one
+pub(crate) fn like_match(pattern: &str, text: &str) -> bool {
+    enum Token {
+        Any,
+        One,
+        Char(char),
+    }
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            '%' => Token::Any,
+            '_' => Token::One,
+            '\\' => Token::Char(chars.next().unwrap_or('\\')),
+            c => Token::Char(c),
+        });
+    }
+    let text: Vec<char> = text.chars().collect();
+    // greedy match, backtracking to the last % on a mismatch
+    let (mut t, mut p) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match tokens.get(p) {
+            Some(Token::Any) => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(Token::One) => {
+                p += 1;
+                t += 1;
+            }
+            Some(Token::Char(c)) if *c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((any_p, any_t)) => {
+                    p = any_p + 1;
+                    t = any_t + 1;
+                    backtrack = Some((any_p, any_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    tokens[p..].iter().all(|token| matches!(token, Token::Any))
+}
+
+// Parameters of a Gravatar QR code image
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct QrOptions {
+    // image width and height in pixels
+    pub(crate) size: i64,
+    // 1 for the classic code, 3 for the modern rounded style
+    pub(crate) version: i64,
+    // center image: 'user' avatar, 'gravatar' logo or 'none'
+    pub(crate) kind: String,
+}
+
+impl Default for QrOptions {
+    fn default() -> Self {
+        Self {
+            size: 80,
+            version: 1,
+            kind: "user".to_owned(),
+        }
+    }
+}
+
+impl QrOptions {
+    pub(crate) fn validate(&self) -> Result<(), FdwError> {
+        if !(1..=1000).contains(&self.size) {
+            return Err(format!("Invalid QR code size {}. Expected 1 to 1000 pixels.", self.size));
+        }
+        if !matches!(self.version, 1 | 3) {
+            return Err(format!("Invalid QR code version {}. Expected 1 or 3.", self.version));
+        }
+        if !matches!(self.kind.as_str(), "user" | "gravatar" | "none") {
+            return Err(format!("Invalid QR code type '{}'. Expected 'user', 'gravatar' or 'none'.", self.kind));
+        }
+        Ok(())
+    }
+
+    // URL of the PNG QR code linking to the profile of `hash`
+    pub(crate) fn url(&self, api_root: &str, hash: &str) -> String {
+        format!(
+            "{}/qr-code/{}?size={}&version={}&type={}",
+            api_root, hash, self.size, self.version, self.kind
+        )
+    }
+}
+
+const DEMO_FIRST_NAMES: &[&str] = &[
+    "Ada", "Bruno", "Chiara", "Dmitri", "Elena", "Farid", "Grace", "Hiro", "Ines", "Jonas", "Kemi", "Luca", "Maya",
+    "Nils", "Olivia", "Pablo",
+];
+const DEMO_LAST_NAMES: &[&str] = &[
+    "Andersen", "Baptiste", "Costa", "Dubois", "Eriksen", "Fischer", "Garcia", "Haddad", "Ivanova", "Jensen",
+    "Kowalski", "Larsen", "Moreau", "Nakamura", "Okafor", "Petrov",
+];
+const DEMO_LOCATIONS: &[(&str, &str)] = &[
+    ("Lisbon, Portugal", "Europe/Lisbon"),
+    ("Toronto, Canada", "America/Toronto"),
+    ("Nairobi, Kenya", "Africa/Nairobi"),
+    ("Osaka, Japan", "Asia/Tokyo"),
+    ("Melbourne, Australia", "Australia/Melbourne"),
+    ("Austin, USA", "America/Chicago"),
+    ("Berlin, Germany", "Europe/Berlin"),
+    ("São Paulo, Brazil", "America/Sao_Paulo"),
+];
+const DEMO_JOBS: &[&str] = &[
+    "Software Engineer",
+    "Product Designer",
+    "Data Analyst",
+    "Community Manager",
+    "Technical Writer",
+    "Support Lead",
+    "Marketing Manager",
+    "Photographer",
+];
+const DEMO_COMPANIES: &[&str] = &[
+    "Acme Corp",
+    "Globex",
+    "Initech",
+    "Umbrella Labs",
+    "Stark Studio",
+    "Wayne Media",
+    "Hooli",
+    "Vandelay Industries",
+];
+const DEMO_PRONOUNS: &[&str] = &["she/her", "he/him", "they/them", ""];
+
+// Synthetic but plausible profile derived from the hash, so the same email
+// always gets the same fake person. Avatars are identicons and links point
+// to example.com, nothing refers to a real Gravatar user.
+pub(crate) fn demo_profile(hash: &str) -> JsonValue {
+    // byte `n` of the hash, as a pseudo-random pick
+    let byte = |n: usize| {
+        hash.get(n * 2..n * 2 + 2)
+            .and_then(|hex| usize::from_str_radix(hex, 16).ok())
+            .unwrap_or(n)
+    };
+    let pick = |n: usize, choices: &[&'static str]| choices[byte(n) % choices.len()];
+
+    let first_name = pick(0, DEMO_FIRST_NAMES);
+    let last_name = pick(1, DEMO_LAST_NAMES);
+    let (location, timezone) = DEMO_LOCATIONS[byte(2) % DEMO_LOCATIONS.len()];
+    let job_title = pick(3, DEMO_JOBS);
+    let company = pick(4, DEMO_COMPANIES);
+    let short_hash = hash.get(..8).unwrap_or(hash);
+    let registered_year = 2008 + byte(5) % 16;
+    let edited_year = registered_year + byte(6) % (2025 - registered_year);
+    let month = 1 + byte(7) % 12;
+    let day = 1 + byte(8) % 28;
+
+    serde_json::json!({
+        "hash": hash,
+        "display_name": format!("{} {}", first_name, last_name),
+        "profile_url": format!("https://example.com/demo/{}", short_hash),
+        "avatar_url": format!("https://gravatar.com/avatar/{}?d=identicon&f=y", hash),
+        "avatar_alt_text": format!("Identicon for {} {}", first_name, last_name),
+        "location": location,
+        "description": format!("{} at {}. Demo profile, not a real person.", job_title, company),
+        "job_title": job_title,
+        "company": company,
+        "verified_accounts": [],
+        "pronunciation": "",
+        "pronouns": pick(9, DEMO_PRONOUNS),
+        "timezone": timezone,
+        "languages": [],
+        "first_name": first_name,
+        "last_name": last_name,
+        "is_organization": false,
+        "links": [{"label": "Website", "url": format!("https://example.com/demo/{}/site", short_hash)}],
+        "interests": [],
+        "payments": {"links": [], "crypto_wallets": []},
+        "contact_info": null,
+        "number_verified_accounts": 0,
+        "last_profile_edit": format!("{}-{:02}-{:02}T12:00:00Z", edited_year, month, day),
+        "registration_date": format!("{}-{:02}-{:02}T09:30:00Z", registered_year, month, day),
+    })
+}
+
+// Profile fields returned as text
+pub(crate) const TEXT_FIELDS: &[&str] = &[
+    "hash",
+    "email",
+    "display_name",
+    "profile_url",
+    "avatar_url",
+    "avatar_alt_text",
+    "location",
+    "description",
+    "job_title",
+    "company",
+    "pronunciation",
+    "pronouns",
+    "timezone",
+    "first_name",
+    "last_name",
+    // Postgres converts these strings to timestamps
+    "last_profile_edit",
+    "registration_date",
+];
+
+// Profile fields returned as JSON
+pub(crate) const JSON_FIELDS: &[&str] = &[
+    "verified_accounts",
+    "languages",
+    "links",
+    "interests",
+    "payments",
+    "contact_info",
+];
+
+// Free-form profile fields cleaned up by the `sanitize_text` option
+pub(crate) const SANITIZED_FIELDS: &[&str] = &["display_name", "description", "job_title"];
+
+// User-supplied text as plain text: HTML entities decoded (`&amp;`,
+// `&#39;`, `&#x2019;`, ...) and control characters other than newlines
+// and tabs removed
+pub(crate) fn sanitize_text(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('&') {
+        text.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| Some((html_entity(&rest[1..end])?, end)));
+        match decoded {
+            Some((c, end)) => {
+                text.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                text.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    text.push_str(rest);
+    text.retain(|c| !c.is_control() || c == '\n' || c == '\t');
+    text
+}
+
+// Character of an HTML entity name or number, without `&` and `;`
+fn html_entity(entity: &str) -> Option<char> {
+    let code = if let Some(hex) = entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X")) {
+        u32::from_str_radix(hex, 16).ok()?
+    } else if let Some(decimal) = entity.strip_prefix('#') {
+        decimal.parse().ok()?
+    } else {
+        return match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            _ => None,
+        };
+    };
+    char::from_u32(code)
+}
+
+// Whether a JSON field holds no data: an empty array or object, or an object
+// of empty members like `payments` of a profile without payment links
+pub(crate) fn is_empty_json(value: &JsonValue) -> bool {
+    match value {
+        JsonValue::Array(items) => items.is_empty(),
+        JsonValue::Object(members) => members.values().all(|member| member.is_null() || is_empty_json(member)),
+        _ => false,
+    }
+}
+
+// Top-level fields whose value differs between two payloads of a profile, as
+// {"field": {"old": ..., "new": ...}}. Missing fields compare as null.
+pub(crate) fn profile_diff(old: &JsonValue, new: &JsonValue) -> serde_json::Map<String, JsonValue> {
+    let empty = serde_json::Map::new();
+    let old = old.as_object().unwrap_or(&empty);
+    let new = new.as_object().unwrap_or(&empty);
+    let fields: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    fields
+        .into_iter()
+        .filter_map(|field| {
+            let before = old.get(field).unwrap_or(&JsonValue::Null);
+            let after = new.get(field).unwrap_or(&JsonValue::Null);
+            (before != after).then(|| (field.clone(), serde_json::json!({"old": before, "new": after})))
+        })
+        .collect()
+}
+
+// Cell for a profile field. Unknown columns are looked up by name and
+// converted according to the column type.
+pub(crate) fn profile_cell(profile: &JsonValue, column: &str, type_oid: TypeOid) -> Option<Cell> {
+    let value = profile.get(column)?;
+    if TEXT_FIELDS.contains(&column) {
+        return value.as_str().map(|s| Cell::String(s.to_string()));
+    }
+    if JSON_FIELDS.contains(&column) {
+        return Some(Cell::Json(value.to_string()));
+    }
+    match column {
+        "is_organization" => value.as_bool().map(Cell::Bool),
+        "number_verified_accounts" => value.as_i64().map(Cell::I64),
+        _ => match type_oid {
+            TypeOid::Bool => value.as_bool().map(Cell::Bool),
+            TypeOid::String => value.as_str().map(|s| Cell::String(s.to_string())),
+            TypeOid::I32 => value.as_i64().map(|i| Cell::I32(i as i32)),
+            TypeOid::I64 => value.as_i64().map(Cell::I64),
+            TypeOid::Json => Some(Cell::Json(value.to_string())),
+            _ => None,
+        },
+    }
+}
+
+// Cell for an epoch seconds value, as bigint or timestamp depending on the column type
+pub(crate) fn epoch_cell(secs: i64, type_oid: TypeOid) -> Cell {
+    match type_oid {
+        TypeOid::I64 => Cell::I64(secs),
+        TypeOid::Timestamp => Cell::Timestamp(secs * 1_000_000),
+        _ => Cell::Timestamptz(secs * 1_000_000),
+    }
+}
+
+// Escape a vCard property value (RFC 6350, section 3.4)
+fn vcard_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace(';', "\\;")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+// Fold a content line at 75 octets, continuation lines start with a space
+fn vcard_fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / 74 * 3);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded
+}
+
+// Profile slug, the first path segment of profile_url
+// (https://gravatar.com/<username>)
+pub(crate) fn profile_username(profile: &JsonValue) -> Option<String> {
+    let url = profile.get("profile_url")?.as_str()?;
+    let (_, rest) = url.split_once("://")?;
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    let (_, path) = rest.split_once('/')?;
+    path.split('/').find(|segment| !segment.is_empty()).map(str::to_owned)
+}
+
+// RFC 6350 vCard of a profile: name, organization, title, photo, profile
+// and link URLs. Empty fields are left out.
+pub(crate) fn profile_vcard(profile: &JsonValue) -> String {
+    let text = |field: &str| profile.get(field).and_then(|v| v.as_str()).filter(|s| !s.is_empty());
+    let first_name = text("first_name").unwrap_or_default();
+    let last_name = text("last_name").unwrap_or_default();
+    let full_name = text("display_name")
+        .map(str::to_owned)
+        .unwrap_or_else(|| format!("{} {}", first_name, last_name).trim().to_owned());
+
+    let mut lines = vec![
+        "BEGIN:VCARD".to_owned(),
+        "VERSION:4.0".to_owned(),
+        format!("FN:{}", vcard_escape(&full_name)),
+        format!("N:{};{};;;", vcard_escape(last_name), vcard_escape(first_name)),
+    ];
+    let mut push = |property: &str, value: Option<&str>| {
+        if let Some(value) = value {
+            lines.push(format!("{}:{}", property, vcard_escape(value)));
+        }
+    };
+    push("EMAIL", text("email"));
+    push("ORG", text("company"));
+    push("TITLE", text("job_title"));
+    push("NOTE", text("description"));
+    push("PHOTO", text("avatar_url"));
+    push("URL", text("profile_url"));
+    let links = profile.get("links").and_then(|v| v.as_array()).into_iter().flatten();
+    for url in links.filter_map(|link| link.get("url").and_then(|v| v.as_str())) {
+        push("URL", Some(url));
+    }
+    lines.push("END:VCARD".to_owned());
+
+    lines.iter().map(|line| vcard_fold(line)).collect::<Vec<_>>().join("\r\n") + "\r\n"
+}
+
+// Columns of the profile tables
+const PROFILE_COLUMNS: &[(&str, &str)] = &[
+    ("hash", "text"),
+    ("email", "text"),
+    ("display_name", "text"),
+    ("profile_url", "text"),
+    ("avatar_url", "text"),
+    ("avatar_alt_text", "text"),
+    ("location", "text"),
+    ("description", "text"),
+    ("job_title", "text"),
+    ("company", "text"),
+    ("verified_accounts", "jsonb"),
+    ("pronunciation", "text"),
+    ("pronouns", "text"),
+    ("timezone", "text"),
+    ("languages", "jsonb"),
+    ("first_name", "text"),
+    ("last_name", "text"),
+    ("is_organization", "bool"),
+    ("links", "jsonb"),
+    ("interests", "jsonb"),
+    ("payments", "jsonb"),
+    ("contact_info", "jsonb"),
+    ("number_verified_accounts", "int"),
+    ("last_profile_edit", "timestamp"),
+    ("registration_date", "timestamp"),
+    ("json", "jsonb"),
+];
+
+// Profile fields the API only returns to authenticated requests
+pub(crate) const AUTHENTICATED_FIELDS: &[&str] = &[
+    "timezone",
+    "languages",
+    "first_name",
+    "last_name",
+    "is_organization",
+    "links",
+    "interests",
+    "payments",
+    "contact_info",
+    "gallery",
+    "number_verified_accounts",
+    "last_profile_edit",
+    "registration_date",
+];
+
+// Recommended columns and Postgres types of each table, as emitted by the
+// 'schema' table. Metadata columns (attempts, etag, ...) are left out.
+pub(crate) const TABLE_COLUMNS: &[(&str, &[(&str, &str)])] = &[
+    ("profiles", PROFILE_COLUMNS),
+    (
+        "me",
+        &[
+            ("hash", "text"),
+            ("display_name", "text"),
+            ("first_name", "text"),
+            ("last_name", "text"),
+            ("location", "text"),
+            ("description", "text"),
+            ("pronunciation", "text"),
+            ("pronouns", "text"),
+            ("job_title", "text"),
+            ("company", "text"),
+            ("json", "jsonb"),
+        ],
+    ),
+    (
+        "avatars",
+        &[
+            ("image_id", "text"),
+            ("image_url", "text"),
+            ("rating", "text"),
+            ("alt_text", "text"),
+            ("selected", "bool"),
+            ("updated_date", "timestamp"),
+        ],
+    ),
+    ("prefetch", &[("email", "text")]),
+    (
+        "qr",
+        &[
+            ("email", "text"),
+            ("hash", "text"),
+            ("size", "int"),
+            ("version", "int"),
+            ("type", "text"),
+            ("qr_url", "text"),
+        ],
+    ),
+    ("schema", &[("table_name", "text"), ("ddl", "text")]),
+    (
+        "fields",
+        &[("field_name", "text"), ("json_path", "text"), ("pg_type", "text"), ("derived", "bool")],
+    ),
+    (
+        "version",
+        &[
+            ("crate_name", "text"),
+            ("crate_version", "text"),
+            ("git_hash", "text"),
+            ("host_version_requirement", "text"),
+            ("api_url", "text"),
+        ],
+    ),
+    (
+        "health",
+        &[
+            ("status", "text"),
+            ("status_code", "int"),
+            ("authenticated", "bool"),
+            ("ratelimit_remaining", "bigint"),
+            ("error", "text"),
+        ],
+    ),
+    (
+        "stats",
+        &[
+            ("loaded_at", "timestamptz"),
+            ("scans", "bigint"),
+            ("requests", "bigint"),
+            ("bytes_received", "bigint"),
+            ("fetched", "bigint"),
+            ("not_found", "bigint"),
+            ("errors", "bigint"),
+            ("cache_hits", "bigint"),
+            ("last_error_code", "int"),
+            ("last_error", "text"),
+            ("last_error_at", "timestamptz"),
+            ("last_error_hash", "text"),
+        ],
+    ),
+    (
+        "usage",
+        &[
+            ("credential", "text"),
+            ("key_number", "int"),
+            ("quota", "text"),
+            ("requests", "bigint"),
+            ("quota_limit", "bigint"),
+            ("quota_remaining", "bigint"),
+            ("quota_used", "bigint"),
+            ("resets_at", "timestamptz"),
+        ],
+    ),
+    (
+        "all_interests",
+        &[
+            ("email", "text"),
+            ("hash", "text"),
+            ("interest_id", "bigint"),
+            ("name", "text"),
+            ("source", "text"),
+        ],
+    ),
+    (
+        "links",
+        &[
+            ("email", "text"),
+            ("hash", "text"),
+            ("position", "int"),
+            ("label", "text"),
+            ("url", "text"),
+        ],
+    ),
+    (
+        "profiles_raw",
+        &[
+            ("email", "text"),
+            ("hash", "text"),
+            ("status_code", "int"),
+            ("body", "text"),
+            ("headers", "jsonb"),
+            ("request_url", "text"),
+            ("error", "text"),
+        ],
+    ),
+    (
+        "profiles_public",
+        &[
+            ("hash", "text"),
+            ("email", "text"),
+            ("display_name", "text"),
+            ("profile_url", "text"),
+            ("avatar_url", "text"),
+            ("avatar_alt_text", "text"),
+            ("location", "text"),
+            ("description", "text"),
+            ("job_title", "text"),
+            ("company", "text"),
+            ("verified_accounts", "jsonb"),
+            ("pronunciation", "text"),
+            ("pronouns", "text"),
+            ("json", "jsonb"),
+        ],
+    ),
+    ("profiles_full", PROFILE_COLUMNS),
+    (
+        "cache_entries",
+        &[
+            ("hash", "text"),
+            ("api_url", "text"),
+            ("fetched_at", "timestamptz"),
+            ("status", "text"),
+            ("ttl_remaining", "bigint"),
+            ("etag", "text"),
+            ("entry", "jsonb"),
+        ],
+    ),
+];
+
+// Columns computed by the FDW rather than read from the profile payload,
+// with their recommended Postgres types
+pub(crate) const DERIVED_COLUMNS: &[(&str, &str)] = &[
+    ("parse_error", "bool"),
+    ("error", "text"),
+    ("attempts", "int"),
+    ("fetched_at", "timestamptz"),
+    ("max_age", "bigint"),
+    ("emails", "text"),
+    ("api_key_id", "text"),
+    ("server_label", "text"),
+    ("ratelimit_limit", "bigint"),
+    ("ratelimit_remaining", "bigint"),
+    ("ratelimit_reset", "bigint"),
+    ("etag", "text"),
+    ("content_language", "text"),
+    ("response_headers", "jsonb"),
+    ("from_cache", "bool"),
+    ("changed_since_last_fetch", "bool"),
+    ("changed_fields", "jsonb"),
+    ("profile_source", "text"),
+    ("request_url", "text"),
+    ("pseudonym", "text"),
+    ("vcard", "text"),
+    ("username", "text"),
+    ("inferred_interests", "jsonb"),
+    ("exists", "bool"),
+];
+
+// Top-level fields every v3 profile response carries, public or authenticated
+const REQUIRED_PROFILE_FIELDS: &[&str] = &[
+    "hash",
+    "display_name",
+    "profile_url",
+    "avatar_url",
+    "avatar_alt_text",
+    "location",
+    "description",
+    "job_title",
+    "company",
+    "verified_accounts",
+    "pronunciation",
+    "pronouns",
+];
+
+// Fields of the profile responses of a scan that the column mapping doesn't
+// expect, either unknown to it or missing, reported in one warning per scan
+#[derive(Debug, Default)]
+pub(crate) struct SchemaDrift {
+    unknown: BTreeSet<String>,
+    missing: BTreeSet<&'static str>,
+}
+
+impl SchemaDrift {
+    pub(crate) fn observe(&mut self, profile: &JsonValue) {
+        let Some(fields) = profile.as_object() else {
+            return;
+        };
+        let known = TABLE_COLUMNS
+            .iter()
+            .find(|(table, _)| *table == "profiles")
+            .map(|(_, columns)| *columns)
+            .unwrap_or_default();
+        for field in fields.keys() {
+            if !known.iter().any(|(name, _)| name == field) {
+                self.unknown.insert(field.clone());
+            }
+        }
+        for field in REQUIRED_PROFILE_FIELDS {
+            if !fields.contains_key(*field) {
+                self.missing.insert(field);
+            }
+        }
+    }
+
+    pub(crate) fn warning(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if !self.unknown.is_empty() {
+            let unknown: Vec<&str> = self.unknown.iter().map(String::as_str).collect();
+            parts.push(format!("unknown fields not mapped to any column: {}", unknown.join(", ")));
+        }
+        if !self.missing.is_empty() {
+            let missing: Vec<&str> = self.missing.iter().copied().collect();
+            parts.push(format!("expected fields missing, their columns are NULL: {}", missing.join(", ")));
+        }
+        (!parts.is_empty()).then(|| format!("Gravatar API responses differ from the known schema; {}", parts.join("; ")))
+    }
+}
+
+// Bundled JSON Schema of v3 profile responses, for `validate_responses`
+pub(crate) const PROFILE_SCHEMA: &str = include_str!("profile_schema.json");
+
+// Where `value` deviates from `schema`, as "$.path: problem" lines. Only the
+// keywords the bundled schema uses are supported: type, required,
+// properties and items.
+pub(crate) fn schema_violations(schema: &JsonValue, value: &JsonValue) -> Vec<String> {
+    let mut violations = Vec::new();
+    check_schema(schema, value, "$", &mut violations);
+    violations
+}
+
+fn check_schema(schema: &JsonValue, value: &JsonValue, path: &str, violations: &mut Vec<String>) {
+    let types: Vec<&str> = match schema.get("type") {
+        Some(JsonValue::String(name)) => vec![name.as_str()],
+        Some(JsonValue::Array(names)) => names.iter().filter_map(|name| name.as_str()).collect(),
+        _ => Vec::new(),
+    };
+    if !types.is_empty() && !types.iter().any(|name| json_type_matches(name, value)) {
+        violations.push(format!("{}: expected {}, got {}", path, types.join(" or "), json_type_name(value)));
+        return;
+    }
+    if let Some(fields) = value.as_object() {
+        let required = schema.get("required").and_then(|v| v.as_array());
+        for field in required.into_iter().flatten().filter_map(|v| v.as_str()) {
+            if !fields.contains_key(field) {
+                violations.push(format!("{}.{}: required field missing", path, field));
+            }
+        }
+        let properties = schema.get("properties").and_then(|v| v.as_object());
+        for (field, field_schema) in properties.into_iter().flatten() {
+            if let Some(field_value) = fields.get(field) {
+                check_schema(field_schema, field_value, &format!("{}.{}", path, field), violations);
+            }
+        }
+    }
+    if let (Some(items), Some(values)) = (schema.get("items"), value.as_array()) {
+        for (idx, item) in values.iter().enumerate() {
+            check_schema(items, item, &format!("{}[{}]", path, idx), violations);
+        }
+    }
+}
+
+fn json_type_matches(name: &str, value: &JsonValue) -> bool {
+    match name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => true,
+    }
+}
+
+fn json_type_name(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Null => "null",
+        JsonValue::Bool(_) => "boolean",
+        JsonValue::Number(_) => "number",
+        JsonValue::String(_) => "string",
+        JsonValue::Array(_) => "array",
+        JsonValue::Object(_) => "object",
+    }
+}
+
+// Rows of the 'fields' table: every column the profiles table can map, the
+// JSON path it is read from (None for derived columns) and its type
+pub(crate) fn profile_fields() -> Vec<(&'static str, Option<String>, &'static str)> {
+    let payload = TABLE_COLUMNS
+        .iter()
+        .find(|(table, _)| *table == "profiles")
+        .map(|(_, columns)| *columns)
+        .unwrap_or_default();
+    let payload = payload.iter().map(|(name, type_name)| {
+        let path = if *name == "json" { "$".to_owned() } else { format!("$.{}", name) };
+        (*name, Some(path), *type_name)
+    });
+    let derived = DERIVED_COLUMNS.iter().map(|(name, type_name)| (*name, None, *type_name));
+    payload.chain(derived).collect()
+}
+
+// Column identifying rows of a writable table; the host passes its value
+// as the rowid to update/delete
+pub(crate) fn rowid_column(table: &str) -> Option<&'static str> {
+    match table {
+        "me" => Some("hash"),
+        "avatars" => Some("image_id"),
+        "prefetch" => Some("email"),
+        _ => None,
+    }
+}
+
+// Postgres identifier, quoted unless it is a plain lowercase name
+fn quote_ident(name: &str) -> String {
+    let plain = name.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+        && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if plain {
+        name.to_owned()
+    } else {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+// CREATE FOREIGN TABLE statement for `table` in `schema` on `server`
+pub(crate) fn recommended_ddl(schema: &str, server: &str, table: &str, columns: &[(&str, &str)]) -> String {
+    let columns = columns
+        .iter()
+        .map(|(name, type_name)| format!("  {} {}", name, type_name))
+        .collect::<Vec<_>>()
+        .join(",\n");
+    let mut options = vec![format!("table '{}'", table)];
+    if let Some(rowid_column) = rowid_column(table) {
+        options.push(format!("rowid_column '{}'", rowid_column));
+    }
+    format!(
+        "create foreign table {}.{} (\n{}\n)\nserver {}\noptions (\n  {}\n);",
+        quote_ident(schema),
+        table,
+        columns,
+        quote_ident(server),
+        options.join(",\n  ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    struct FakeHost(HashMap<String, String>);
+
+    impl Host for FakeHost {
+        fn vault_secret(&self, secret_id: &str) -> Option<String> {
+            self.0.get(secret_id).cloned()
+        }
+    }
+
+    impl OptionSource for HashMap<String, String> {
+        fn get_option(&self, key: &str) -> Option<String> {
+            self.get(key).cloned()
+        }
+    }
+
+    struct FakeQual {
+        field: &'static str,
+        operator: &'static str,
+        use_or: bool,
+        value: Value,
+        param_id: Option<u32>,
+    }
+
+    impl QualLike for FakeQual {
+        fn field(&self) -> String {
+            self.field.to_owned()
+        }
+        fn operator(&self) -> String {
+            self.operator.to_owned()
+        }
+        fn use_or(&self) -> bool {
+            self.use_or
+        }
+        fn value(&self) -> Value {
+            self.value.clone()
+        }
+        fn param_id(&self) -> Option<u32> {
+            self.param_id
+        }
+    }
+
+    fn email_eq(email: &str) -> FakeQual {
+        FakeQual {
+            field: "email",
+            operator: "=",
+            use_or: false,
+            value: Value::Cell(Cell::String(email.to_owned())),
+            param_id: None,
+        }
+    }
+
+    fn email_in(emails: &[&str]) -> FakeQual {
+        FakeQual {
+            field: "email",
+            operator: "=",
+            use_or: true,
+            value: Value::Array(emails.iter().map(|e| Cell::String(e.to_string())).collect()),
+            param_id: None,
+        }
+    }
+
+    fn options(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn hash_email_normalizes_case_and_whitespace() {
+        let expected = "973dfe463ec85785f5f95af5ba3906eedb2d931c24e69824a89ea65dba4e813b";
+        assert_eq!(hash_email("test@example.com"), expected);
+        assert_eq!(hash_email("  Test@Example.COM "), expected);
+    }
+
+    #[test]
+    fn hash_email_md5_normalizes_case_and_whitespace() {
+        assert_eq!(hash_email_md5(" MyEmailAddress@example.com "), "0bc83cb571cd1c50ba6f3e8a78ef1346");
+    }
+
+    #[test]
+    fn hmac_matches_rfc_4231_vectors() {
+        assert_eq!(
+            hmac_sha256_hex(b"Jefe", b"what do ya want for nothing?"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        // keys longer than the block size are hashed first
+        assert_eq!(
+            hmac_sha256_hex(&[0xaa; 131], b"Test Using Larger Than Block-Size Key - Hash Key First"),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+
+    #[test]
+    fn form_encode_escapes_reserved_characters() {
+        assert_eq!(form_encode("a-b_c.d~e"), "a-b_c.d~e");
+        assert_eq!(form_encode("a b&c=d/é"), "a%20b%26c%3Dd%2F%C3%A9");
+    }
+
+    #[test]
+    fn headers_are_matched_case_insensitively() {
+        let headers = vec![("X-RateLimit-Limit".to_owned(), " 100 ".to_owned())];
+        assert_eq!(find_header(&headers, "x-ratelimit-limit"), Some(" 100 "));
+        assert_eq!(header_i64(&headers, "x-ratelimit-limit"), Some(100));
+        assert_eq!(header_i64(&headers, "x-ratelimit-reset"), None);
+    }
+
+    #[test]
+    fn response_key_is_the_last_path_segment() {
+        assert_eq!(response_key("https://api.gravatar.com/v3/profiles/abc123"), "abc123");
+        assert_eq!(response_key("https://api.gravatar.com/v3/me/profile?x=1"), "profile");
+        assert_eq!(response_key("https://api.gravatar.com/v3/me/avatars#top"), "avatars");
+    }
+
+    #[test]
+    fn rate_limit_window_tracks_reset_only_when_exhausted() {
+        let mut window = RateLimitWindow::default();
+        let exhausted = vec![
+            ("x-ratelimit-remaining".to_owned(), "0".to_owned()),
+            ("x-ratelimit-reset".to_owned(), "1700000000".to_owned()),
+        ];
+        window.track(&exhausted);
+        assert_eq!(window.reset_at, Some(1700000000));
+
+        let available = vec![
+            ("x-ratelimit-remaining".to_owned(), "5".to_owned()),
+            ("x-ratelimit-reset".to_owned(), "1700000000".to_owned()),
+        ];
+        window.track(&available);
+        assert_eq!(window.reset_at, None);
+    }
+
+    #[test]
+    fn retry_policy_backs_off_exponentially() {
+        let policy = RetryPolicy {
+            max_retries: 2,
+            backoff_ms: 100,
+            schedule: Vec::new(),
+        };
+        assert_eq!(policy.delay_ms(0), 100);
+        assert_eq!(policy.delay_ms(2), 400);
+        assert!(policy.should_retry(0, 429));
+        assert!(policy.should_retry(1, 503));
+        assert!(!policy.should_retry(2, 503));
+        assert!(!policy.should_retry(0, 404));
+    }
+
+    #[test]
+    fn daily_requests_reset_at_midnight_utc() {
+        // 2024-01-01T23:59:00Z
+        let before_midnight = 1_704_153_540;
+        let mut requests = DailyRequests::default();
+        requests.record(before_midnight);
+        requests.record(before_midnight + 30);
+        assert_eq!(requests.today(before_midnight + 59), 2);
+        assert_eq!(DailyRequests::resets_in(before_midnight), 60);
+        assert_eq!(requests.today(before_midnight + 60), 0);
+        requests.record(before_midnight + 60);
+        assert_eq!(requests.today(before_midnight + 61), 1);
+    }
+
+    #[test]
+    fn retry_schedule_overrides_backoff() {
+        let schedule = parse_retry_schedule("1s, 5s,30s").unwrap();
+        assert_eq!(schedule, vec![1000, 5000, 30_000]);
+        assert_eq!(parse_retry_schedule("250ms,2m,100"), Some(vec![250, 120_000, 100]));
+        assert_eq!(parse_retry_schedule("1s,,5s"), None);
+        assert_eq!(parse_retry_schedule("soon"), None);
+
+        let policy = RetryPolicy {
+            max_retries: 4,
+            backoff_ms: 100,
+            schedule,
+        };
+        assert_eq!(policy.delay_ms(0), 1000);
+        assert_eq!(policy.delay_ms(2), 30_000);
+        assert_eq!(policy.delay_ms(3), 30_000);
+    }
+
+    #[test]
+    fn secret_source_prefers_direct_then_id() {
+        let host = FakeHost(options(&[("uuid-1", "from-id")]));
+
+        let opts = options(&[("api_key", "direct"), ("api_key_id", "uuid-1")]);
+        let source = SecretSource::from_options(&opts, "api_key").unwrap().unwrap();
+        assert!(!source.is_vault());
+        assert_eq!(source.resolve("API key", &host).unwrap(), "direct");
+
+        let opts = options(&[("api_key_id", "uuid-1"), ("api_key_name", "my-key")]);
+        let source = SecretSource::from_options(&opts, "api_key").unwrap().unwrap();
+        assert!(source.is_vault());
+        assert_eq!(source.resolve("API key", &host).unwrap(), "from-id");
+
+        assert!(SecretSource::from_options(&options(&[]), "api_key").unwrap().is_none());
+    }
+
+    #[test]
+    fn secret_source_rejects_vault_secret_names() {
+        let err = SecretSource::from_options(&options(&[("api_key_name", "my-key")]), "api_key").err().unwrap();
+        assert!(err.starts_with("Option 'api_key_name' is not supported by the Wrappers 0.1 host"), "{}", err);
+        assert!(err.contains("Use 'api_key_id'"), "{}", err);
+    }
+
+    #[test]
+    fn secret_source_reports_missing_vault_secrets() {
+        let host = FakeHost(HashMap::new());
+        let err = SecretSource::VaultId("uuid-2".to_owned()).resolve("API key", &host).unwrap_err();
+        assert_eq!(err, "Failed to retrieve API key from Vault using ID: uuid-2");
+    }
+
+    #[test]
+    fn extract_emails_handles_equality_and_in_lists() {
+        assert_eq!(extract_emails(&[email_eq("a@example.com")]).unwrap(), vec!["a@example.com"]);
+        assert_eq!(
+            extract_emails(&[email_in(&["a@example.com", "b@example.com", "a@example.com"])]).unwrap(),
+            vec!["a@example.com", "b@example.com"]
+        );
+        assert!(extract_emails::<FakeQual>(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn query_params_are_appended_encoded() {
+        let params = parse_query_params(r#"{"team": "data eng", "region": 2, "beta": true}"#).unwrap();
+        assert_eq!(params.len(), 3);
+        let team = [("team".to_owned(), "data eng".to_owned())];
+        assert_eq!(
+            append_query_params("https://proxy.example.com/v3/profiles/abc", &team),
+            "https://proxy.example.com/v3/profiles/abc?team=data%20eng"
+        );
+        let url = append_query_params("https://gravatar.com/avatar/abc?d=404", &params);
+        assert!(url.starts_with("https://gravatar.com/avatar/abc?d=404&"));
+        assert!(["team=data%20eng", "region=2", "beta=true"].iter().all(|param| url.contains(param)));
+        assert_eq!(parse_query_params(r#"{"nested": {"a": 1}}"#), None);
+        assert_eq!(parse_query_params("[1, 2]"), None);
+    }
+
+    #[test]
+    fn qual_pushdown_depends_on_table_field_and_operator() {
+        let qual = |field: &'static str, operator: &'static str| FakeQual {
+            field,
+            operator,
+            ..email_eq("a@example.com")
+        };
+        assert!(qual_pushed_down("profiles", &email_in(&["a@example.com"])));
+        assert!(qual_pushed_down("profiles_full", &qual("max_age", "=")));
+        assert!(qual_pushed_down("links", &qual("url", "~~")));
+        assert!(qual_pushed_down("usage", &qual("api_key_id", "=")));
+        assert!(!qual_pushed_down("links", &qual("max_age", "=")));
+        assert!(!qual_pushed_down("profiles", &qual("display_name", "=")));
+        assert!(!qual_pushed_down("all_interests", &qual("source", "<>")));
+        assert!(!qual_pushed_down("stats", &email_eq("a@example.com")));
+
+        assert_eq!(describe_qual(&email_in(&["a@example.com"])), "email IN");
+        assert_eq!(describe_qual(&qual("url", "~~*")), "url ILIKE");
+        assert_eq!(describe_qual(&qual("fetched_at", ">=")), "fetched_at >=");
+    }
+
+    #[test]
+    fn extract_emails_reads_parameters() {
+        let param = FakeQual {
+            param_id: Some(1),
+            ..email_eq("a@example.com")
+        };
+        assert_eq!(extract_emails(&[param]).unwrap(), vec!["a@example.com"]);
+        let unevaluated = FakeQual {
+            value: Value::Cell(Cell::I64(0)),
+            param_id: Some(1),
+            ..email_eq("")
+        };
+        assert!(extract_emails(&[unevaluated]).unwrap_err().starts_with("The email condition uses query parameter $1"));
+    }
+
+    #[test]
+    fn extract_emails_ignores_other_columns() {
+        let other = FakeQual {
+            field: "display_name",
+            operator: "like",
+            use_or: false,
+            value: Value::Cell(Cell::String("x%".to_owned())),
+            param_id: None,
+        };
+        assert_eq!(extract_emails(&[other, email_eq("a@example.com")]).unwrap(), vec!["a@example.com"]);
+    }
+
+    #[test]
+    fn extract_emails_rejects_unsupported_filters() {
+        let like = FakeQual {
+            operator: "~~",
+            ..email_eq("%@example.com")
+        };
+        let err = extract_emails(&[like]).unwrap_err();
+        assert!(err.starts_with("Unsupported operator LIKE ('~~') on the email column."), "{}", err);
+        assert!(err.contains("look them up with IN"));
+
+        let not_equal = FakeQual {
+            operator: "<>",
+            ..email_eq("a@example.com")
+        };
+        assert!(extract_emails(&[not_equal]).unwrap_err().starts_with("Unsupported operator != ('<>') on"));
+        let not_in = FakeQual {
+            operator: "<>",
+            ..email_in(&["a@example.com", "b@example.com"])
+        };
+        assert!(extract_emails(&[not_in]).unwrap_err().starts_with("Unsupported operator NOT IN ('<>') on"));
+
+        let err = extract_emails(&[email_eq("a@example.com"), email_eq("b@example.com")]).unwrap_err();
+        assert!(err.contains("Found 2 email conditions"));
+    }
+
+    #[test]
+    fn environments_parse_and_preset_policies() {
+        assert_eq!(Environment::parse("production"), Some(Environment::Production));
+        assert_eq!(Environment::parse("Staging"), None);
+        let staging = Environment::parse("staging").unwrap();
+        assert!(staging.base_url().starts_with("https://"));
+        assert_ne!(staging.base_url(), Environment::Production.base_url());
+        assert!(staging.max_rate_limit_wait_secs() > Environment::Production.max_rate_limit_wait_secs());
+        assert_eq!(Environment::Production.write_retry().max_retries, 0);
+        assert!(staging.write_retry().should_retry(0, 503));
+    }
+
+    #[test]
+    fn recommended_ddl_lists_columns_and_options() {
+        let ddl = recommended_ddl("gravatar", "gravatar_server", "me", &[("hash", "text"), ("json", "jsonb")]);
+        assert_eq!(
+            ddl,
+            "create foreign table gravatar.me (\n  hash text,\n  json jsonb\n)\nserver gravatar_server\n\
+             options (\n  table 'me',\n  rowid_column 'hash'\n);"
+        );
+        let ddl = recommended_ddl("Gravatar", "my-server", "profiles", &[("hash", "text")]);
+        assert!(ddl.starts_with("create foreign table \"Gravatar\".profiles"));
+        assert!(ddl.ends_with("server \"my-server\"\noptions (\n  table 'profiles'\n);"));
+    }
+
+    #[test]
+    fn vcards_escape_values_and_fold_long_lines() {
+        let profile = serde_json::json!({
+            "display_name": "Jane, Doe",
+            "first_name": "Jane",
+            "last_name": "Doe",
+            "company": "Acme; Inc",
+            "job_title": "",
+            "description": "x".repeat(100),
+            "links": [{"label": "Blog", "url": "https://example.com/blog"}],
+        });
+        let vcard = profile_vcard(&profile);
+        assert!(vcard.starts_with("BEGIN:VCARD\r\nVERSION:4.0\r\nFN:Jane\\, Doe\r\nN:Doe;Jane;;;\r\n"));
+        assert!(vcard.contains("\r\nORG:Acme\\; Inc\r\n"));
+        assert!(!vcard.contains("TITLE"));
+        assert!(vcard.contains("\r\nURL:https://example.com/blog\r\n"));
+        assert!(vcard.ends_with("END:VCARD\r\n"));
+        assert!(vcard.split("\r\n").all(|line| line.len() <= 75));
+    }
+
+    #[test]
+    fn extract_max_age_reads_the_pseudo_qual() {
+        let max_age = |operator, value| FakeQual {
+            field: "max_age",
+            operator,
+            use_or: false,
+            value: Value::Cell(value),
+            param_id: None,
+        };
+        assert_eq!(extract_max_age(&[email_eq("a@example.com")]).unwrap(), None);
+        assert_eq!(extract_max_age(&[max_age("=", Cell::I64(60))]).unwrap(), Some(60));
+        assert_eq!(extract_max_age(&[max_age("=", Cell::I32(5))]).unwrap(), Some(5));
+        assert!(extract_max_age(&[max_age("<", Cell::I64(60))]).is_err());
+        assert!(extract_max_age(&[max_age("=", Cell::I64(-1))]).is_err());
+    }
+
+    #[test]
+    fn like_patterns_match_like_postgres() {
+        assert!(like_match("%github%", "https://github.com/jane"));
+        assert!(like_match("Blog", "Blog"));
+        assert!(!like_match("Blog", "Blogs"));
+        assert!(like_match("B_og%", "Blog posts"));
+        assert!(like_match("%.com", "a.b.com"));
+        assert!(!like_match("%.com", "a.com.au"));
+        assert!(like_match("100\\%", "100%"));
+        assert!(!like_match("100\\%", "1000"));
+        assert!(like_match("%", ""));
+    }
+
+    #[test]
+    fn text_filters_apply_equality_and_like_quals() {
+        let label = FakeQual {
+            field: "label",
+            ..email_eq("Blog")
+        };
+        let url = FakeQual {
+            field: "url",
+            operator: "~~*",
+            ..email_eq("%GITHUB%")
+        };
+        let filters = TextFilter::from_quals(&[label, url, email_eq("a@example.com")], &["label", "url"]);
+        assert_eq!(filters.len(), 2);
+        let blog = serde_json::json!({ "label": "Blog", "url": "https://github.com/jane" });
+        let other = serde_json::json!({ "label": "Blog", "url": "https://example.com" });
+        assert!(filters.iter().all(|filter| filter.matches(&blog)));
+        assert!(!filters.iter().all(|filter| filter.matches(&other)));
+    }
+
+    #[test]
+    fn extract_email_list_splits_the_pseudo_qual() {
+        let emails = |operator, list: &str| FakeQual {
+            field: "emails",
+            operator,
+            ..email_eq(list)
+        };
+        assert_eq!(extract_email_list(&[email_eq("a@example.com")]).unwrap(), None);
+        let (list, parsed) = extract_email_list(&[emails("=", "a@example.com; b@example.com,a@example.com;")])
+            .unwrap()
+            .unwrap();
+        assert_eq!(list, "a@example.com; b@example.com,a@example.com;");
+        assert_eq!(parsed, vec!["a@example.com", "b@example.com"]);
+        assert!(extract_email_list(&[emails("~~", "%@example.com")]).is_err());
+    }
+
+    #[test]
+    fn profile_fields_cover_payload_and_derived_columns() {
+        let fields = profile_fields();
+        assert!(fields.contains(&("display_name", Some("$.display_name".to_owned()), "text")));
+        assert!(fields.contains(&("json", Some("$".to_owned()), "jsonb")));
+        assert!(fields.contains(&("attempts", None, "int")));
+        let mut names: Vec<_> = fields.iter().map(|(name, _, _)| *name).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), fields.len());
+    }
+
+    #[test]
+    fn compare_profiles_follows_order_by_semantics() {
+        let key = |field: &str, reversed, nulls_first| SortKey {
+            field: field.to_owned(),
+            reversed,
+            nulls_first,
+        };
+        let mut profiles = vec![
+            serde_json::json!({"display_name": "b", "n": 2}),
+            serde_json::json!({"display_name": null, "n": 10}),
+            serde_json::json!({"display_name": "a", "n": 2}),
+        ];
+        let names = |profiles: &[JsonValue]| profiles.iter().map(|p| p["display_name"].clone()).collect::<Vec<_>>();
+
+        profiles.sort_by(|a, b| compare_profiles(&[key("display_name", false, false)], a, b));
+        assert_eq!(names(&profiles), vec!["a".into(), "b".into(), JsonValue::Null]);
+
+        // DESC NULLS FIRST keeps NULLs first
+        profiles.sort_by(|a, b| compare_profiles(&[key("display_name", true, true)], a, b));
+        assert_eq!(names(&profiles), vec![JsonValue::Null, "b".into(), "a".into()]);
+
+        // numbers compare numerically, ties fall through to the next key
+        profiles.sort_by(|a, b| compare_profiles(&[key("n", false, false), key("display_name", false, false)], a, b));
+        assert_eq!(names(&profiles), vec!["a".into(), "b".into(), JsonValue::Null]);
+    }
+
+    #[test]
+    fn extract_emails_sees_through_lower_and_trim() {
+        let wrapped = |field| FakeQual {
+            field,
+            ..email_eq("A@Example.com")
+        };
+        for field in ["lower(email)", "trim(email)", "lower(btrim(email))", "\"email\""] {
+            assert_eq!(extract_emails(&[wrapped(field)]).unwrap(), vec!["A@Example.com"], "{}", field);
+        }
+        assert!(extract_emails(&[wrapped("upper(email)")]).unwrap().is_empty());
+        assert!(extract_emails(&[wrapped("lower(email_backup)")]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn qr_options_validate_and_build_urls() {
+        let options = QrOptions::default();
+        assert!(options.validate().is_ok());
+        assert_eq!(
+            options.url("https://api.gravatar.com/v3", "abc"),
+            "https://api.gravatar.com/v3/qr-code/abc?size=80&version=1&type=user"
+        );
+        let invalid = [
+            QrOptions { size: 0, ..QrOptions::default() },
+            QrOptions { version: 2, ..QrOptions::default() },
+            QrOptions { kind: "logo".to_owned(), ..QrOptions::default() },
+        ];
+        assert!(invalid.iter().all(|options| options.validate().is_err()));
+    }
+
+    #[test]
+    fn email_normalization_strips_plus_tags_on_request() {
+        let keep = EmailNormalization::default();
+        let strip = EmailNormalization {
+            strip_plus_tags: true,
+            ..EmailNormalization::default()
+        };
+        assert_eq!(keep.normalize(" User+News@Example.com "), "user+news@example.com");
+        assert_eq!(strip.normalize(" User+News@Example.com "), "user@example.com");
+        assert_eq!(strip.normalize("+only@example.com"), "+only@example.com");
+        assert_eq!(strip.normalize("no-at-sign"), "no-at-sign");
+        assert_eq!(strip.hash("test+x@example.com"), hash_email("test@example.com"));
+        assert_eq!(keep.hash("Test@Example.com"), hash_email("test@example.com"));
+    }
+
+    #[test]
+    fn idn_policies_normalize_unicode_emails() {
+        let with = |idn| EmailNormalization {
+            idn,
+            ..EmailNormalization::default()
+        };
+        // decomposed ü (u + combining diaeresis) in the local part
+        let email = "Ju\u{308}rgen@Bücher.example";
+        assert_eq!(with(IdnPolicy::Preserve).normalize(email), "ju\u{308}rgen@bücher.example");
+        assert_eq!(with(IdnPolicy::Nfc).normalize(email), "jürgen@bücher.example");
+        assert_eq!(with(IdnPolicy::Punycode).normalize(email), "jürgen@xn--bcher-kva.example");
+        assert_eq!(domain_to_ascii("münchen.de"), "xn--mnchen-3ya.de");
+        assert_eq!(domain_to_ascii("MÜNCHEN.de"), "xn--mnchen-3ya.de");
+        assert_eq!(domain_to_ascii(&format!("{}ü.de", "a".repeat(63))), format!("{}ü.de", "a".repeat(63)));
+        assert_eq!(IdnPolicy::parse("punycode"), Some(IdnPolicy::Punycode));
+        assert_eq!(IdnPolicy::parse("idna"), None);
+    }
+
+    #[test]
+    fn email_syntax_is_checked_before_hashing() {
+        for email in ["test@example.com", " Test.User+tag@mail.example.co.uk ", "o'brien@example.com", "ü@exämple.de"] {
+            assert!(valid_email_syntax(email), "{}", email);
+        }
+        for email in [
+            "",
+            "not an email",
+            "@example.com",
+            "user@",
+            "user@localhost",
+            "user@@example.com",
+            "us er@example.com",
+            ".user@example.com",
+            "user..name@example.com",
+            "user@-example.com",
+            "user@example..com",
+            "1 OR 1=1",
+        ] {
+            assert!(!valid_email_syntax(email), "{}", email);
+        }
+        assert!(!valid_email_syntax(&format!("{}@example.com", "a".repeat(65))));
+    }
+
+    #[test]
+    fn demo_profiles_are_deterministic_and_synthetic() {
+        let hash = hash_email("test@example.com");
+        let profile = demo_profile(&hash);
+        assert_eq!(profile, demo_profile(&hash));
+        assert_ne!(profile["display_name"], demo_profile(&hash_email("other@example.com"))["display_name"]);
+        assert_eq!(profile["hash"].as_str(), Some(hash.as_str()));
+        assert!(profile["avatar_url"].as_str().unwrap().contains("d=identicon&f=y"));
+        assert!(profile["registration_date"].as_str().unwrap() <= profile["last_profile_edit"].as_str().unwrap());
+        // malformed hashes still produce a profile
+        assert!(demo_profile("xyz").get("display_name").is_some());
+    }
+
+    #[test]
+    fn profile_cell_maps_known_fields() {
+        let profile = serde_json::json!({
+            "display_name": "Jane",
+            "is_organization": false,
+            "number_verified_accounts": 3,
+            "links": [{"label": "Blog"}],
+            "registration_date": "2020-01-01T00:00:00Z",
+        });
+        assert!(matches!(profile_cell(&profile, "display_name", TypeOid::String), Some(Cell::String(s)) if s == "Jane"));
+        assert!(matches!(profile_cell(&profile, "is_organization", TypeOid::Bool), Some(Cell::Bool(false))));
+        assert!(matches!(profile_cell(&profile, "number_verified_accounts", TypeOid::I64), Some(Cell::I64(3))));
+        assert!(matches!(profile_cell(&profile, "links", TypeOid::Json), Some(Cell::Json(s)) if s == r#"[{"label":"Blog"}]"#));
+        assert!(matches!(profile_cell(&profile, "registration_date", TypeOid::Timestamp), Some(Cell::String(_))));
+        assert!(profile_cell(&profile, "location", TypeOid::String).is_none());
+    }
+
+    #[test]
+    fn profile_cell_converts_unknown_columns_by_type() {
+        let profile = serde_json::json!({"score": 7, "flag": true, "extra": {"a": 1}});
+        assert!(matches!(profile_cell(&profile, "score", TypeOid::I32), Some(Cell::I32(7))));
+        assert!(matches!(profile_cell(&profile, "flag", TypeOid::Bool), Some(Cell::Bool(true))));
+        assert!(matches!(profile_cell(&profile, "extra", TypeOid::Json), Some(Cell::Json(_))));
+        // type mismatches yield NULL rather than an error
+        assert!(profile_cell(&profile, "score", TypeOid::String).is_none());
+        assert!(profile_cell(&profile, "extra", TypeOid::Date).is_none());
+    }
+
+    #[test]
+    fn profile_diff_lists_changed_fields() {
+        let old = serde_json::json!({"display_name": "Old", "location": "Paris", "links": [], "job_title": "Dev"});
+        let new = serde_json::json!({"display_name": "New", "location": "Paris", "links": [], "company": "Acme"});
+        assert_eq!(
+            JsonValue::Object(profile_diff(&old, &new)),
+            serde_json::json!({
+                "company": {"old": null, "new": "Acme"},
+                "display_name": {"old": "Old", "new": "New"},
+                "job_title": {"old": "Dev", "new": null},
+            })
+        );
+        assert!(profile_diff(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn empty_json_includes_objects_of_empty_members() {
+        assert!(is_empty_json(&serde_json::json!([])));
+        assert!(is_empty_json(&serde_json::json!({})));
+        assert!(is_empty_json(&serde_json::json!({"links": [], "crypto_wallets": []})));
+        assert!(!is_empty_json(&serde_json::json!(["en"])));
+        assert!(!is_empty_json(&serde_json::json!({"links": [{"url": "https://example.com"}]})));
+        assert!(!is_empty_json(&serde_json::json!("")));
+    }
+
+    #[test]
+    fn sanitize_text_decodes_entities_and_drops_control_characters() {
+        assert_eq!(sanitize_text("Tom &amp; Jerry&#39;s &lt;b&gt;shop&lt;/b&gt;"), "Tom & Jerry's <b>shop</b>");
+        assert_eq!(sanitize_text("It&#x2019;s\u{7}\r here\nand\tthere\u{0}"), "It\u{2019}s here\nand\tthere");
+        assert_eq!(sanitize_text("AT&T & co; &unknown; &#xZZ;"), "AT&T & co; &unknown; &#xZZ;");
+        assert_eq!(sanitize_text("&amp;amp;"), "&amp;");
+    }
+
+    #[test]
+    fn schema_drift_lists_unknown_and_missing_fields_once() {
+        let mut drift = SchemaDrift::default();
+        assert!(drift.warning().is_none());
+
+        let mut complete = serde_json::Map::new();
+        for field in REQUIRED_PROFILE_FIELDS {
+            complete.insert(field.to_string(), JsonValue::String(String::new()));
+        }
+        drift.observe(&JsonValue::Object(complete.clone()));
+        assert!(drift.warning().is_none());
+
+        complete.insert("badges".to_owned(), JsonValue::Array(vec![]));
+        complete.remove("pronouns");
+        drift.observe(&JsonValue::Object(complete.clone()));
+        drift.observe(&JsonValue::Object(complete));
+        assert_eq!(
+            drift.warning().as_deref(),
+            Some(
+                "Gravatar API responses differ from the known schema; unknown fields not mapped to any column: badges; \
+                 expected fields missing, their columns are NULL: pronouns"
+            )
+        );
+    }
+
+    #[test]
+    fn bundled_profile_schema_reports_deviating_paths() {
+        let schema: JsonValue = serde_json::from_str(PROFILE_SCHEMA).unwrap();
+        let mut profile = serde_json::Map::new();
+        for field in REQUIRED_PROFILE_FIELDS {
+            profile.insert(field.to_string(), JsonValue::String(String::new()));
+        }
+        profile.insert("verified_accounts".to_owned(), serde_json::json!([]));
+        profile.insert("registration_date".to_owned(), JsonValue::Null);
+        assert!(schema_violations(&schema, &JsonValue::Object(profile.clone())).is_empty());
+
+        profile.remove("company");
+        profile.insert("is_organization".to_owned(), JsonValue::String("no".to_owned()));
+        profile.insert("links".to_owned(), serde_json::json!([{"label": "Blog", "url": null}, {"label": "Shop"}]));
+        assert_eq!(
+            schema_violations(&schema, &JsonValue::Object(profile)),
+            vec![
+                "$.company: required field missing",
+                "$.is_organization: expected boolean, got string",
+                "$.links[0].url: expected string, got null",
+                "$.links[1].url: required field missing",
+            ]
+        );
+    }
+
+    #[test]
+    fn key_usage_keeps_the_last_reported_quota() {
+        let mut usage = KeyUsage::default();
+        let headers = vec![
+            ("X-RateLimit-Limit".to_owned(), "1000".to_owned()),
+            ("X-RateLimit-Remaining".to_owned(), "990".to_owned()),
+            ("X-RateLimit-Reset".to_owned(), "1700000000".to_owned()),
+        ];
+        usage.record(Some(&headers));
+        usage.record(None);
+        usage.record(Some(&[]));
+        assert_eq!(usage.requests, 3);
+        assert_eq!(usage.used(), Some(10));
+        assert_eq!(usage.reset_at, Some(1_700_000_000));
+        assert_eq!(KeyUsage::default().used(), None);
+    }
+
+    #[test]
+    fn avatar_ratings_order_and_rewrite_urls() {
+        assert!(AvatarRating::parse("PG").unwrap() < AvatarRating::parse("r").unwrap());
+        assert_eq!(AvatarRating::parse("nc-17"), None);
+        let pg = AvatarRating::Pg;
+        assert_eq!(pg.avatar_url("https://0.gravatar.com/avatar/abc"), "https://0.gravatar.com/avatar/abc?r=pg");
+        assert_eq!(
+            pg.avatar_url("https://0.gravatar.com/avatar/abc?s=80&r=x"),
+            "https://0.gravatar.com/avatar/abc?s=80&r=pg"
+        );
+    }
+
+    #[test]
+    fn column_names_map_to_fields_whatever_their_case() {
+        assert_eq!(column_field("display_name"), "display_name");
+        assert_eq!(column_field("Display_Name"), "display_name");
+        assert_eq!(column_field("displayName"), "display_name");
+        assert_eq!(column_field("avatarURL"), "avatar_url");
+        assert_eq!(column_field("JSON"), "json");
+        assert_eq!(column_field("ETag"), "etag");
+        assert_eq!(column_field("lower(Email)"), "lower(email)");
+    }
+
+    #[test]
+    fn usernames_come_from_the_profile_url() {
+        let username = |url: &str| profile_username(&serde_json::json!({ "profile_url": url }));
+        assert_eq!(username("https://gravatar.com/jane.doe").as_deref(), Some("jane.doe"));
+        assert_eq!(username("https://gravatar.com/jane/?utm=x").as_deref(), Some("jane"));
+        assert_eq!(username("https://gravatar.com/"), None);
+        assert_eq!(profile_username(&serde_json::json!({})), None);
+    }
+
+    #[test]
+    fn cdn_avatar_urls_keep_path_and_query() {
+        let cdn = "https://img.example.com/gravatar/";
+        assert_eq!(
+            cdn_avatar_url("https://0.gravatar.com/avatar/abc?s=80&r=pg", cdn),
+            "https://img.example.com/gravatar/avatar/abc?s=80&r=pg"
+        );
+        assert_eq!(
+            cdn_avatar_url("https://gravatar.com/userimage/1/def.jpeg", cdn),
+            "https://img.example.com/gravatar/userimage/1/def.jpeg"
+        );
+        assert_eq!(cdn_avatar_url("https://example.com/me.png", cdn), "https://example.com/me.png");
+        assert_eq!(cdn_avatar_url("https://evilgravatar.com/avatar/abc", cdn), "https://evilgravatar.com/avatar/abc");
+    }
+
+    #[test]
+    fn avatar_base_url_follows_environment_and_proxies() {
+        assert_eq!(avatar_base_url(Environment::Production.base_url()), "https://gravatar.com/avatar");
+        assert_eq!(avatar_base_url(Environment::Staging.base_url()), "https://staging-api.gravatar.com/avatar");
+        assert_eq!(
+            avatar_base_url("http://proxy.internal:8080/gravatar/v3/profiles/"),
+            "http://proxy.internal:8080/gravatar/avatar"
+        );
+    }
+}
@@ -4,7 +4,11 @@
 // the GNU General Public License v3.0.
 #[allow(warnings)]
 mod bindings;
+mod fdw_core;
+mod tables;
 use serde_json::Value as JsonValue;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
 use sha2::{Digest, Sha256};
 
 use bindings::{
@@ -12,48 +16,3329 @@ use bindings::{
     supabase::wrappers::{
         http,
         time,
-        types::{Cell, Context, FdwError, FdwResult, OptionsType, Row, TypeOid, Value},
+        types::{self, Context, FdwError, FdwResult, Options, OptionsType, Qual, Row},
         utils,
     },
 };
+use fdw_core::{
+    append_query_params, avatar_base_url, cdn_avatar_url, column_field, compare_profiles, demo_profile, describe_qual,
+    epoch_cell, extract_email_list, extract_emails, extract_max_age, find_header, form_encode, hash_email, header_i64,
+    hmac_sha256_hex, is_empty_json, parse_query_params, parse_retry_schedule, profile_cell,
+    profile_diff, profile_fields, profile_username, profile_vcard, pseudo_qual, qual_pushed_down, recommended_ddl, response_key,
+    rowid_column, sanitize_text, schema_violations, valid_email_syntax, AUTHENTICATED_FIELDS, AvatarRating, Cell,
+    DailyRequests, EmailNormalization, Environment, ErrorAction, Host, IdnPolicy, JSON_FIELDS, KeyUsage,
+    OptionSource, PROFILE_SCHEMA, QrOptions, QualLike, RateLimitWindow, RetryPolicy, SANITIZED_FIELDS, SchemaDrift,
+    SecretSource, SortKey, TABLE_COLUMNS, TextFilter, TypeOid, Value,
+};
+use tables::{table_scan, unsupported_table_error, ProfileAccess};
+
+// Host calls used by the core logic
+struct WasmHost;
+
+impl Host for WasmHost {
+    fn vault_secret(&self, secret_id: &str) -> Option<String> {
+        utils::get_vault_secret(secret_id)
+    }
+}
+
+impl OptionSource for Options {
+    fn get_option(&self, key: &str) -> Option<String> {
+        self.get(key)
+    }
+}
+
+impl QualLike for Qual {
+    fn field(&self) -> String {
+        column_field(&Qual::field(self))
+    }
+    fn operator(&self) -> String {
+        Qual::operator(self)
+    }
+    fn use_or(&self) -> bool {
+        Qual::use_or(self)
+    }
+    fn value(&self) -> Value {
+        Qual::value(self).into()
+    }
+    fn param_id(&self) -> Option<u32> {
+        Qual::param(self).map(|param| param.id)
+    }
+}
+
+// Conversions between the core's plain types and the WIT bindings
+impl From<types::Cell> for Cell {
+    fn from(cell: types::Cell) -> Self {
+        match cell {
+            types::Cell::Bool(v) => Cell::Bool(v),
+            types::Cell::I8(v) => Cell::I8(v),
+            types::Cell::I16(v) => Cell::I16(v),
+            types::Cell::F32(v) => Cell::F32(v),
+            types::Cell::I32(v) => Cell::I32(v),
+            types::Cell::F64(v) => Cell::F64(v),
+            types::Cell::I64(v) => Cell::I64(v),
+            types::Cell::Numeric(v) => Cell::Numeric(v),
+            types::Cell::String(v) => Cell::String(v),
+            types::Cell::Date(v) => Cell::Date(v),
+            types::Cell::Timestamp(v) => Cell::Timestamp(v),
+            types::Cell::Timestamptz(v) => Cell::Timestamptz(v),
+            types::Cell::Json(v) => Cell::Json(v),
+        }
+    }
+}
+
+impl From<Cell> for types::Cell {
+    fn from(cell: Cell) -> Self {
+        match cell {
+            Cell::Bool(v) => types::Cell::Bool(v),
+            Cell::I8(v) => types::Cell::I8(v),
+            Cell::I16(v) => types::Cell::I16(v),
+            Cell::F32(v) => types::Cell::F32(v),
+            Cell::I32(v) => types::Cell::I32(v),
+            Cell::F64(v) => types::Cell::F64(v),
+            Cell::I64(v) => types::Cell::I64(v),
+            Cell::Numeric(v) => types::Cell::Numeric(v),
+            Cell::String(v) => types::Cell::String(v),
+            Cell::Date(v) => types::Cell::Date(v),
+            Cell::Timestamp(v) => types::Cell::Timestamp(v),
+            Cell::Timestamptz(v) => types::Cell::Timestamptz(v),
+            Cell::Json(v) => types::Cell::Json(v),
+        }
+    }
+}
+
+impl From<types::TypeOid> for TypeOid {
+    fn from(type_oid: types::TypeOid) -> Self {
+        match type_oid {
+            types::TypeOid::Bool => TypeOid::Bool,
+            types::TypeOid::I8 => TypeOid::I8,
+            types::TypeOid::I16 => TypeOid::I16,
+            types::TypeOid::F32 => TypeOid::F32,
+            types::TypeOid::I32 => TypeOid::I32,
+            types::TypeOid::F64 => TypeOid::F64,
+            types::TypeOid::I64 => TypeOid::I64,
+            types::TypeOid::Numeric => TypeOid::Numeric,
+            types::TypeOid::String => TypeOid::String,
+            types::TypeOid::Date => TypeOid::Date,
+            types::TypeOid::Timestamp => TypeOid::Timestamp,
+            types::TypeOid::Timestamptz => TypeOid::Timestamptz,
+            types::TypeOid::Json => TypeOid::Json,
+        }
+    }
+}
+
+impl From<types::Value> for Value {
+    fn from(value: types::Value) -> Self {
+        match value {
+            types::Value::Cell(cell) => Value::Cell(cell.into()),
+            types::Value::Array(cells) => Value::Array(cells.into_iter().map(Cell::from).collect()),
+        }
+    }
+}
+
+// Cells of a row passed in by INSERT or UPDATE
+fn row_cells(row: &Row) -> Vec<Option<Cell>> {
+    row.cells().into_iter().map(|cell| cell.map(Cell::from)).collect()
+}
+
+// Add a cell to the row emitted by iter_scan
+fn push_cell(row: &Row, cell: Option<&Cell>) {
+    row.push(cell.cloned().map(types::Cell::from).as_ref());
+}
+
+// Per-scan lookup outcome and performance counters
+#[derive(Debug, Default, Clone)]
+struct ScanStats {
+    fetched: usize,
+    not_found: usize,
+    failed: usize,
+    requests: usize,
+    bytes_received: usize,
+    started_at: i64,
+    status: StatusBreakdown,
+}
+
+// Number of lookups per outcome
+#[derive(Debug, Default, Clone)]
+struct StatusBreakdown {
+    ok: usize,
+    not_found: usize,
+    rate_limited: usize,
+    server_error: usize,
+    other_status: usize,
+    network_error: usize,
+    cache_hit: usize,
+}
+
+impl StatusBreakdown {
+    fn record(&mut self, resp: &Result<http::Response, FdwError>) {
+        match resp {
+            Ok(resp) => match resp.status_code {
+                200 => self.ok += 1,
+                404 => self.not_found += 1,
+                429 => self.rate_limited += 1,
+                500..=599 => self.server_error += 1,
+                _ => self.other_status += 1,
+            },
+            Err(_) => self.network_error += 1,
+        }
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "200: {}, 404: {}, 429: {}, 5xx: {}, other status: {}, network error: {}, cache hit: {}",
+            self.ok,
+            self.not_found,
+            self.rate_limited,
+            self.server_error,
+            self.other_status,
+            self.network_error,
+            self.cache_hit
+        )
+    }
+}
+
+impl ScanStats {
+    fn total(&self) -> usize {
+        self.fetched + self.not_found + self.failed
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "{} fetched, {} not found, {} failed",
+            self.fetched, self.not_found, self.failed
+        )
+    }
+
+    fn record_request(&mut self, resp: &Result<http::Response, FdwError>) {
+        self.requests += 1;
+        self.bytes_received += resp.as_ref().map(|resp| resp.body.len()).unwrap_or_default();
+        self.status.record(resp);
+    }
+
+    fn performance_summary(&self, now: i64) -> String {
+        format!(
+            "{} requests, {} bytes received, {} s total",
+            self.requests,
+            self.bytes_received,
+            now - self.started_at
+        )
+    }
+
+    // Both summaries as one JSON object, for scan_metrics_format 'json'
+    fn metrics_json(&self, now: i64, scan_id: Option<&str>) -> JsonValue {
+        let lookups = self.total();
+        serde_json::json!({
+            "event": "gravatar_scan",
+            "scan_id": scan_id,
+            "fetched": self.fetched,
+            "not_found": self.not_found,
+            "failed": self.failed,
+            "error_rate": if lookups > 0 { self.failed as f64 / lookups as f64 } else { 0.0 },
+            "requests": self.requests,
+            "bytes_received": self.bytes_received,
+            "duration_secs": now - self.started_at,
+            "status": {
+                "200": self.status.ok,
+                "404": self.status.not_found,
+                "429": self.status.rate_limited,
+                "5xx": self.status.server_error,
+                "other": self.status.other_status,
+                "network_error": self.status.network_error,
+                "cache_hit": self.status.cache_hit,
+            },
+        })
+    }
+}
+
+// Totals of every scan since the wasm instance loaded, for the 'stats' table
+#[derive(Debug, Default)]
+struct LifetimeStats {
+    loaded_at: i64,
+    scans: usize,
+    requests: usize,
+    bytes_received: usize,
+    fetched: usize,
+    not_found: usize,
+    errors: usize,
+    cache_hits: usize,
+    // epoch seconds of the last `telemetry_url` report, 0 before the first
+    telemetry_sent_at: i64,
+    last_error: Option<LastError>,
+}
+
+// Most recent failed request, kept for the 'stats' table
+#[derive(Debug, Clone)]
+struct LastError {
+    // HTTP status, None for network errors
+    status_code: Option<u16>,
+    message: String,
+    at: i64,
+    // profile hash of a failed lookup
+    hash: Option<String>,
+}
+
+impl LifetimeStats {
+    fn add(&mut self, scan: &ScanStats) {
+        self.scans += 1;
+        self.requests += scan.requests;
+        self.bytes_received += scan.bytes_received;
+        self.fetched += scan.fetched;
+        self.not_found += scan.not_found;
+        self.errors += scan.failed;
+        self.cache_hits += scan.status.cache_hit;
+    }
+
+    // Aggregate counters only: no emails, hashes, URLs or credentials
+    fn telemetry_payload(&self, now: i64) -> JsonValue {
+        let lookups = self.fetched + self.not_found + self.errors;
+        serde_json::json!({
+            "crate_version": env!("CARGO_PKG_VERSION"),
+            "uptime_secs": now - self.loaded_at,
+            "scans": self.scans,
+            "requests": self.requests,
+            "cache_hits": self.cache_hits,
+            "errors": self.errors,
+            "error_rate": if lookups > 0 { self.errors as f64 / lookups as f64 } else { 0.0 },
+        })
+    }
+}
+
+// Details about the HTTP exchange that produced a profile
+#[derive(Debug, Default, Clone)]
+struct FetchInfo {
+    // URL the profile was fetched from (hash based, never the raw email)
+    url: String,
+    // response headers
+    headers: Vec<(String, String)>,
+    // served from the profile cache instead of a live API call
+    from_cache: bool,
+    // epoch seconds when the response was received, None without a request
+    fetched_at: Option<i64>,
+    // requests made for the profile including retries, 0 when served from cache
+    attempts: u32,
+    // fields the API returned differently from the cached copy it replaced,
+    // see profile_diff; None without a cached copy to compare with
+    changed_fields: Option<serde_json::Map<String, JsonValue>>,
+}
+
+impl FetchInfo {
+    fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    // Numeric header value, e.g. rate limit counters
+    fn header_i64(&self, name: &str) -> Option<i64> {
+        header_i64(&self.headers, name)
+    }
+}
+
+// A successful profile response kept for reuse
+#[derive(Debug)]
+struct CacheEntry {
+    profile: JsonValue,
+    headers: Vec<(String, String)>,
+    fetched_at: i64,
+    // endpoint the profile came from, see ProfileCache::base_url
+    base_url: String,
+}
+
+impl CacheEntry {
+    // Serialized form listed by the cache_entries table and read back by the
+    // cache_preload server option
+    fn to_json(&self, hash: &str) -> JsonValue {
+        let headers: serde_json::Map<String, JsonValue> = self
+            .headers
+            .iter()
+            .map(|(name, value)| (name.to_lowercase(), JsonValue::String(value.clone())))
+            .collect();
+        serde_json::json!({
+            "hash": hash,
+            "api_url": self.base_url,
+            "fetched_at": self.fetched_at,
+            "headers": headers,
+            "profile": self.profile,
+        })
+    }
+
+    // Inverse of `to_json`, entries without api_url belong to `base_url`
+    fn from_json(value: &JsonValue, base_url: &str) -> Result<(String, CacheEntry), String> {
+        let hash = value.get("hash").and_then(|v| v.as_str()).ok_or("missing 'hash'")?;
+        let fetched_at = value.get("fetched_at").and_then(|v| v.as_i64()).ok_or("missing 'fetched_at'")?;
+        let profile = value.get("profile").filter(|v| v.is_object()).ok_or("missing 'profile' object")?;
+        let headers = value
+            .get("headers")
+            .and_then(|v| v.as_object())
+            .map(|headers| {
+                headers
+                    .iter()
+                    .filter_map(|(name, value)| Some((name.clone(), value.as_str()?.to_owned())))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let entry = CacheEntry {
+            profile: profile.clone(),
+            headers,
+            fetched_at,
+            base_url: value.get("api_url").and_then(|v| v.as_str()).unwrap_or(base_url).to_owned(),
+        };
+        Ok((hash.to_lowercase(), entry))
+    }
+
+    // Conditional request headers revalidating an expired entry: the ETag,
+    // and Last-Modified for intermediaries that strip ETags
+    fn validators(&self) -> Vec<(String, String)> {
+        let mut validators = Vec::new();
+        if let Some(etag) = find_header(&self.headers, "etag") {
+            validators.push(("if-none-match".to_owned(), etag.to_owned()));
+        }
+        if let Some(last_modified) = find_header(&self.headers, "last-modified") {
+            validators.push(("if-modified-since".to_owned(), last_modified.to_owned()));
+        }
+        validators
+    }
+}
+
+// In-memory profile cache keyed by email hash. It lives as long as the wasm
+// instance, which the host may recreate for every query.
+#[derive(Debug, Default)]
+struct ProfileCache {
+    // entry lifetime in seconds, 0 disables caching
+    ttl_secs: i64,
+    entries: HashMap<String, CacheEntry>,
+    // fingerprint of the credential the entries were fetched with, since
+    // different users may see different profile data
+    credential: String,
+    // endpoint of the current scan; tables overriding api_url must not be
+    // served profiles another endpoint returned
+    base_url: String,
+    // set while a scan falls back to public access, whose profiles lack the
+    // authenticated fields and must not outlive the scan
+    frozen: bool,
+    // set while a scan runs with a per-query credential, see use_query_api_key
+    bypassed: bool,
+}
+
+impl ProfileCache {
+    fn enabled(&self) -> bool {
+        self.ttl_secs > 0 && !self.bypassed
+    }
+
+    // Fresh entry for the hash, if any
+    fn get(&self, hash: &str, now: i64) -> Option<&CacheEntry> {
+        self.get_within(hash, now, None)
+    }
+
+    // Fresh entry no older than `max_age` seconds, if any
+    fn get_within(&self, hash: &str, now: i64, max_age: Option<i64>) -> Option<&CacheEntry> {
+        if !self.enabled() {
+            return None;
+        }
+        self.entries
+            .get(hash)
+            .filter(|entry| entry.base_url == self.base_url)
+            .filter(|entry| now - entry.fetched_at < self.ttl_secs)
+            .filter(|entry| max_age.is_none_or(|max_age| now - entry.fetched_at <= max_age))
+    }
+
+    // Entry for the hash whatever its age, to revalidate it
+    fn get_expired(&self, hash: &str) -> Option<&CacheEntry> {
+        self.entries
+            .get(hash)
+            .filter(|entry| self.enabled() && entry.base_url == self.base_url)
+    }
+
+    // The API confirmed an entry is unchanged (HTTP 304): it is fresh again.
+    // A 304 may omit the validators, so the cached ones are kept.
+    fn revalidate(&mut self, hash: &str, headers: Vec<(String, String)>, now: i64) -> Option<&CacheEntry> {
+        let entry = self.entries.get_mut(hash).filter(|entry| entry.base_url == self.base_url)?;
+        for (name, value) in headers {
+            match entry.headers.iter_mut().find(|(cached, _)| cached.eq_ignore_ascii_case(&name)) {
+                Some(header) => header.1 = value,
+                None => entry.headers.push((name, value)),
+            }
+        }
+        entry.fetched_at = now;
+        Some(entry)
+    }
+
+    // Adds serialized entries (a JSON array, see CacheEntry::to_json) for
+    // hashes not cached yet, returning how many were added
+    fn preload(&mut self, serialized: &str) -> Result<usize, String> {
+        let values: Vec<JsonValue> = match serde_json::from_str(serialized) {
+            Ok(JsonValue::Array(values)) => values,
+            Ok(_) => return Err("expected a JSON array of cache entries".to_owned()),
+            Err(err) => return Err(err.to_string()),
+        };
+        let mut added = 0;
+        for (idx, value) in values.iter().enumerate() {
+            let (hash, entry) =
+                CacheEntry::from_json(value, &self.base_url).map_err(|err| format!("entry {}: {}", idx + 1, err))?;
+            if let std::collections::hash_map::Entry::Vacant(slot) = self.entries.entry(hash) {
+                slot.insert(entry);
+                added += 1;
+            }
+        }
+        Ok(added)
+    }
+
+    fn insert(&mut self, hash: String, profile: JsonValue, headers: Vec<(String, String)>, now: i64) {
+        if self.enabled() && !self.frozen {
+            let entry = CacheEntry {
+                profile,
+                headers,
+                fetched_at: now,
+                base_url: self.base_url.clone(),
+            };
+            self.entries.insert(hash, entry);
+        }
+    }
+}
+
+// OAuth2 credentials used to renew an expired access token. No Debug
+// derive: every field but the URL and expiry is secret.
+#[derive(Default)]
+struct OAuthRefresh {
+    token_url: String,
+    client_id: String,
+    client_secret: String,
+    refresh_token: String,
+    // epoch seconds when the current access token expires, if known
+    expires_at: Option<i64>,
+}
+
+impl OAuthRefresh {
+    // Renew slightly early so a token doesn't expire mid-request
+    const EXPIRY_MARGIN_SECS: i64 = 30;
+
+    fn expired(&self, now: i64) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at - Self::EXPIRY_MARGIN_SECS)
+    }
+
+    fn form_body(&self) -> String {
+        let mut body = format!("grant_type=refresh_token&refresh_token={}", form_encode(&self.refresh_token));
+        if !self.client_id.is_empty() {
+            body.push_str(&format!("&client_id={}", form_encode(&self.client_id)));
+        }
+        if !self.client_secret.is_empty() {
+            body.push_str(&format!("&client_secret={}", form_encode(&self.client_secret)));
+        }
+        body
+    }
+}
+
+// A modification buffered until end_modify
+#[derive(Debug)]
+enum PendingWrite {
+    UpdateProfile {
+        hash: String,
+        changes: serde_json::Map<String, JsonValue>,
+    },
+    SelectAvatar {
+        image_id: String,
+    },
+    DeleteAvatar {
+        image_id: String,
+    },
+    Prefetch {
+        email: String,
+    },
+}
+
+impl PendingWrite {
+    fn operation(&self) -> &'static str {
+        match self {
+            PendingWrite::UpdateProfile { .. } => "update_profile",
+            PendingWrite::SelectAvatar { .. } => "select_avatar",
+            PendingWrite::DeleteAvatar { .. } => "delete_avatar",
+            PendingWrite::Prefetch { .. } => "prefetch",
+        }
+    }
+
+    // Profile hash, avatar image_id or email the write applies to
+    fn target(&self) -> &str {
+        match self {
+            PendingWrite::UpdateProfile { hash, .. } => hash,
+            PendingWrite::SelectAvatar { image_id } | PendingWrite::DeleteAvatar { image_id } => image_id,
+            PendingWrite::Prefetch { email } => email,
+        }
+    }
+
+    fn changed_fields(&self) -> Vec<String> {
+        match self {
+            PendingWrite::UpdateProfile { changes, .. } => changes.keys().cloned().collect(),
+            PendingWrite::SelectAvatar { .. } => vec!["selected".to_owned()],
+            PendingWrite::DeleteAvatar { .. } | PendingWrite::Prefetch { .. } => Vec::new(),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            PendingWrite::UpdateProfile { hash, .. } => format!("update profile {}", hash),
+            PendingWrite::SelectAvatar { image_id } => format!("select avatar {}", image_id),
+            PendingWrite::DeleteAvatar { image_id } => format!("delete avatar {}", image_id),
+            PendingWrite::Prefetch { email } => format!("prefetch {}", email),
+        }
+    }
+}
+
+// A profile fetched during begin_scan, waiting to be emitted by iter_scan
+#[derive(Debug, Clone)]
+struct ScannedProfile {
+    profile: JsonValue,
+    // raw response body, kept only when it could not be parsed as JSON
+    raw_body: Option<String>,
+    // why the lookup failed, for error rows
+    error: Option<String>,
+    fetch: FetchInfo,
+    // cells iter_scan built for the row, replacing the data above once the
+    // row was emitted, so a re_scan returns it again
+    cells: Option<Vec<Option<Cell>>>,
+}
+
+impl ScannedProfile {
+    fn parsed(profile: JsonValue, fetch: FetchInfo) -> Self {
+        Self {
+            profile,
+            raw_body: None,
+            error: None,
+            fetch,
+            cells: None,
+        }
+    }
+
+    // Placeholder row for a response body that is not valid JSON
+    fn unparsable(email: &str, hash: String, raw_body: String, fetch: FetchInfo) -> Self {
+        let mut map = serde_json::Map::new();
+        map.insert("email".to_string(), JsonValue::String(email.to_owned()));
+        map.insert("hash".to_string(), JsonValue::String(hash));
+        Self {
+            profile: JsonValue::Object(map),
+            raw_body: Some(raw_body),
+            error: None,
+            fetch,
+            cells: None,
+        }
+    }
+
+    // Placeholder row for a lookup that failed, e.g. with a network error
+    fn failed(email: &str, hash: String, error: String, fetch: FetchInfo) -> Self {
+        let mut map = serde_json::Map::new();
+        map.insert("email".to_string(), JsonValue::String(email.to_owned()));
+        map.insert("hash".to_string(), JsonValue::String(hash));
+        Self {
+            profile: JsonValue::Object(map),
+            raw_body: None,
+            error: Some(error),
+            fetch,
+            cells: None,
+        }
+    }
+
+    fn parse_error(&self) -> bool {
+        self.raw_body.is_some()
+    }
+
+    // Free the payload and headers of an emitted row, keeping its cells
+    fn drain(&mut self, cells: Vec<Option<Cell>>) {
+        *self = Self {
+            profile: JsonValue::Null,
+            raw_body: None,
+            error: None,
+            fetch: FetchInfo::default(),
+            cells: Some(cells),
+        };
+    }
+
+    // Content of the `json` column: the full profile, or the raw body as a
+    // JSON string when parsing failed
+    fn json(&self) -> String {
+        match &self.raw_body {
+            Some(raw_body) => JsonValue::String(raw_body.clone()).to_string(),
+            None => self.profile.to_string(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct GravatarFdw {
+    base_url: String,
+    // api_url of the server, which tables may override for their scans
+    server_base_url: String,
+    headers: Vec<(String, String)>,
+    // credential values that must never show up in logs or errors
+    secrets: Vec<String>,
+    // Vault secret holding the API key, re-read when the key is rejected
+    api_key_source: Option<SecretSource>,
+    // API keys tried in order on 401/429, and the one currently in use
+    api_keys: Vec<String>,
+    api_key_index: usize,
+    // set when authenticating with an OAuth2 access token that can be refreshed
+    oauth: Option<OAuthRefresh>,
+    scanned_profiles: Vec<ScannedProfile>,
+    scan_index: usize,
+    scan_stats: ScanStats,
+    // end scans with one JSON NOTICE instead of the text summaries
+    json_metrics: bool,
+    // returned in the server_label column, telling servers apart in UNION ALL
+    server_label: Option<String>,
+    // short ID prefixed to log messages of the current scan
+    scan_id: Option<String>,
+    scans_started: u64,
+    cache: ProfileCache,
+    // lowercased email domains that may be looked up, empty allows all
+    allowed_domains: Vec<String>,
+    // whole-email pattern queried emails must match, see email_allowed
+    email_pattern: Option<regex_lite::Regex>,
+    // skip emails outside allowed_domains or email_pattern with a warning
+    // instead of failing
+    skip_disallowed_emails: bool,
+    // skip values that aren't shaped like an email with a warning instead of
+    // failing, see valid_email_syntax
+    skip_invalid_emails: bool,
+    // canned responses served instead of calling the API, see `mock_response`
+    mock_responses: Option<serde_json::Map<String, JsonValue>>,
+    // answer lookups with synthetic profiles, see `demo_response`
+    demo_mode: bool,
+    // hashes per lookup request, more than 1 uses the batch endpoint
+    batch_size: usize,
+    // oldest cached profile, in seconds, the current scan accepts
+    max_age: Option<i64>,
+    // value of the `emails` pseudo-qual, echoed in that column
+    email_list: Option<String>,
+    // the current scan selects no columns, e.g. count(*)
+    count_only: bool,
+    // the table options were checked for misspellings since init
+    table_options_checked: bool,
+    // email rewriting applied before hashing
+    normalization: EmailNormalization,
+    // retry lookups that 404 by the email's legacy MD5 hash
+    md5_fallback: bool,
+    // columns of the current scan always returned as NULL
+    blocked_columns: Vec<String>,
+    // most explicit avatar rating the current scan returns, and whether
+    // avatars known to exceed it are NULLed rather than only rewritten
+    max_rating: Option<AvatarRating>,
+    null_over_rated_avatars: bool,
+    // mirror or CDN serving Gravatar images, see apply_avatar_cdn
+    avatar_cdn_base: Option<String>,
+    // hard cap on the rows of the current scan, whatever the LIMIT
+    max_rows: Option<usize>,
+    // what a lookup failing at the network level does to the scan
+    network_error_action: ErrorAction,
+    // what a lookup answered with an unexpected HTTP status does to the scan
+    http_error_action: ErrorAction,
+    // lookup retries after network failures and after HTTP 5xx, counted separately
+    network_retry: RetryPolicy,
+    http_retry: RetryPolicy,
+    // lowercased header carrying the credential, `authorization` by default
+    auth_header: String,
+    // parameters appended to every API request URL, for gateways and proxies
+    query_params: Vec<(String, String)>,
+    // retry reads rejected with 401 without credentials, see http_send_with
+    public_fallback: bool,
+    // URLs answered without credentials in the current scan
+    public_fallback_urls: HashSet<String>,
+    // Vault secret of the `api_key_id` pseudo-qual of the current scan, and
+    // the server credential headers it replaced
+    query_api_key_id: Option<String>,
+    server_headers: Option<Vec<(String, String)>>,
+    // prefix of the credential in `auth_header`, empty to send the bare key
+    auth_scheme: String,
+    // live responses of the current statement, see `flush_recording`
+    recording: Option<serde_json::Map<String, JsonValue>>,
+    // secret keying the HMAC of the pseudonym column
+    pseudonym_key: Option<String>,
+    // return NULL in the json column of the current scan
+    hide_json: bool,
+    // clean up free-form text fields, see fdw_core::sanitize_text
+    sanitize_text: bool,
+    // return NULL for text columns the API left empty
+    nullify_empty_strings: bool,
+    // the current scan selects inferred_interests, which costs a request per row
+    fetch_inferred_interests: bool,
+    // return NULL for JSON profile fields without data, see is_empty_json
+    nullify_empty_json: bool,
+    // log profile hashes instead of emails
+    pii_safe_logging: bool,
+    // emails of the current statement and their hashes, see `redact`
+    email_hashes: HashMap<String, String>,
+    // fingerprint of the credential last checked with verify_credentials
+    verified_credential: String,
+    // credential and base URL last checked with `warmup`
+    warmed_up: String,
+    lifetime: LifetimeStats,
+    // requests and last reported quota per (credential fingerprint, quota)
+    key_usage: HashMap<(String, &'static str), KeyUsage>,
+    // live requests allowed per UTC day, and whether scans then serve cached
+    // profiles only instead of failing
+    daily_request_budget: Option<u64>,
+    budget_cache_only: bool,
+    daily_requests: DailyRequests,
+    // response fields of the current scan the column mapping doesn't expect
+    schema_drift: SchemaDrift,
+    // parsed PROFILE_SCHEMA when `validate_responses` is on
+    profile_schema: Option<JsonValue>,
+    // opt-in endpoint receiving anonymous usage counters, and seconds between reports
+    telemetry_url: Option<String>,
+    telemetry_interval_secs: i64,
+    // reads and writes have separate API quotas, so they are tracked apart
+    read_rate_limit: RateLimitWindow,
+    write_rate_limit: RateLimitWindow,
+    write_retry: RetryPolicy,
+    // longest pause while waiting for an exhausted rate limit to reset
+    max_rate_limit_wait_secs: i64,
+    // table targeted by the current INSERT/UPDATE/DELETE
+    modify_table: String,
+    // writes collected during the statement, sent in end_modify
+    pending_writes: Vec<PendingWrite>,
+    debug: bool,
+}
+
+// Hand-written so credentials in the headers can't leak through debug formatting
+impl std::fmt::Debug for GravatarFdw {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GravatarFdw")
+            .field("base_url", &self.base_url)
+            .field("headers", &self.redacted_headers(&self.headers))
+            .field("scanned_profiles", &self.scanned_profiles.len())
+            .field("scan_index", &self.scan_index)
+            .field("scan_stats", &self.scan_stats)
+            .field("debug", &self.debug)
+            .finish_non_exhaustive()
+    }
+}
+
+// pointer for the static FDW instance
+static mut INSTANCE: *mut GravatarFdw = std::ptr::null_mut::<GravatarFdw>();
+
+impl GravatarFdw {
+    const PROFILES_OBJECT: &'static str = "profiles";
+    const USER_AGENT: &'static str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+    const ME_OBJECT: &'static str = "me";
+    const PREFETCH_OBJECT: &'static str = "prefetch";
+    const AVATARS_OBJECT: &'static str = "avatars";
+    const SCHEMA_OBJECT: &'static str = "schema";
+    const VERSION_OBJECT: &'static str = "version";
+    const HEALTH_OBJECT: &'static str = "health";
+    const FIELDS_OBJECT: &'static str = "fields";
+    const QR_OBJECT: &'static str = "qr";
+    const STATS_OBJECT: &'static str = "stats";
+    const USAGE_OBJECT: &'static str = "usage";
+    const ALL_INTERESTS_OBJECT: &'static str = "all_interests";
+    const PROFILES_RAW_OBJECT: &'static str = "profiles_raw";
+    const CACHE_ENTRIES_OBJECT: &'static str = "cache_entries";
+    const PROFILES_PUBLIC_OBJECT: &'static str = "profiles_public";
+    const PROFILES_FULL_OBJECT: &'static str = "profiles_full";
+    const LINKS_OBJECT: &'static str = "links";
+
+    // Profile fields that can be changed with UPDATE on the 'me' table
+    const PROFILE_UPDATE_FIELDS: &'static [&'static str] = &[
+        "display_name",
+        "first_name",
+        "last_name",
+        "description",
+        "pronunciation",
+        "pronouns",
+        "location",
+        "job_title",
+        "company",
+    ];
+
+    // Options understood by the FDW, used to validate user input
+    const SERVER_OPTIONS: &'static [&'static str] = &[
+        "api_url",
+        "environment",
+        "api_key",
+        "api_key_id",
+        "api_keys",
+        "api_key_ids",
+        "auth_header_name",
+        "auth_failure_action",
+        "auth_scheme",
+        "query_params",
+        "md5_fallback",
+        "oauth_access_token",
+        "oauth_access_token_id",
+        "oauth_refresh_token",
+        "oauth_refresh_token_id",
+        "oauth_client_id",
+        "oauth_client_secret",
+        "oauth_client_secret_id",
+        "oauth_token_url",
+        "cache_ttl",
+        "cache_preload",
+        "batch_size",
+        "batch_protocol",
+        "strip_plus_tags",
+        "idn_policy",
+        "network_error_action",
+        "network_max_retries",
+        "http_error_action",
+        "http_max_retries",
+        "read_retry_backoff_ms",
+        "retry_schedule",
+        "write_max_retries",
+        "write_retry_backoff_ms",
+        "daily_request_budget",
+        "budget_exceeded_action",
+        "verify_credentials",
+        "warmup",
+        "allowed_domains",
+        "email_pattern",
+        "disallowed_email_action",
+        "invalid_email_action",
+        "pii_safe_logging",
+        "scan_metrics_format",
+        "server_label",
+        "avatar_cdn_base",
+        "pseudonym_key",
+        "pseudonym_key_id",
+        "mock_responses",
+        "demo_mode",
+        "record_responses",
+        "validate_responses",
+        "telemetry_url",
+        "telemetry_interval",
+        "debug",
+    ];
+    const TABLE_OPTIONS: &'static [&'static str] = &[
+        "table",
+        "rowid_column",
+        "json_column",
+        "max_age",
+        "email_aliases",
+        "qr_size",
+        "qr_version",
+        "qr_type",
+        "blocked_columns",
+        "max_rating",
+        "rating_action",
+        "max_rows",
+        "api_url",
+        "nullify_empty_strings",
+        "nullify_empty_json",
+        "exists_only",
+        "sanitize_text",
+    ];
+
+    // Number of emails covered by each progress NOTICE in large scans
+    const NOTICE_BATCH_SIZE: usize = 50;
+
+    // Schema violations listed per response by `validate_responses`
+    const MAX_LISTED_VIOLATIONS: usize = 10;
+
+    // Response headers left out of the response_headers column and the
+    // headers of the 'profiles_raw' table
+    const SECRET_RESPONSE_HEADERS: &'static [&'static str] =
+        &["set-cookie", "set-cookie2", "authorization", "proxy-authorization"];
+
+    // Response headers that may carry a request/trace ID, in order of preference
+    const REQUEST_ID_HEADERS: &'static [&'static str] =
+        &["x-request-id", "x-correlation-id", "x-amzn-trace-id", "cf-ray"];
+
+    // initialise FDW instance
+    fn init_instance() {
+        let mut instance = Self::default();
+        unsafe {
+            // keep cached profiles if the host re-initializes this wasm instance
+            if !INSTANCE.is_null() {
+                instance.cache.entries = std::mem::take(&mut (*INSTANCE).cache.entries);
+                instance.cache.credential = std::mem::take(&mut (*INSTANCE).cache.credential);
+                instance.verified_credential = std::mem::take(&mut (*INSTANCE).verified_credential);
+                instance.warmed_up = std::mem::take(&mut (*INSTANCE).warmed_up);
+                instance.lifetime = std::mem::take(&mut (*INSTANCE).lifetime);
+                instance.key_usage = std::mem::take(&mut (*INSTANCE).key_usage);
+                instance.daily_requests = std::mem::take(&mut (*INSTANCE).daily_requests);
+            } else {
+                instance.lifetime.loaded_at = time::epoch_secs();
+            }
+            INSTANCE = Box::leak(Box::new(instance));
+        }
+    }
+
+    fn this_mut() -> &'static mut Self {
+        unsafe { &mut (*INSTANCE) }
+    }
+
+    fn validate_api_url(url: &str) -> FdwResult {
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            return Err(format!("Invalid api_url '{}'. It must start with http:// or https://", url));
+        }
+        Ok(())
+    }
+
+    // `WHERE api_key_id = '<vault secret id>'` runs the scan with that key
+    // instead of the server's, so a multi-tenant platform can route each
+    // query through the tenant's own key. The cache is bypassed, since
+    // entries fetched with one tenant's key must not be served to another.
+    fn use_query_api_key(&mut self, quals: &[Qual]) -> FdwResult {
+        let secret_id = match pseudo_qual(quals, "api_key_id") {
+            Some(Cell::String(secret_id)) => secret_id,
+            Some(_) => return Err("Invalid api_key_id condition. Use api_key_id = '<vault secret id>'.".to_owned()),
+            None => return Ok(()),
+        };
+        let api_key = WasmHost
+            .vault_secret(&secret_id)
+            .filter(|api_key| !api_key.trim().is_empty())
+            .ok_or_else(|| format!("No API key found in Vault for api_key_id '{}'", secret_id))?;
+        self.server_headers = Some(self.headers.clone());
+        self.set_api_key(api_key.trim());
+        self.query_api_key_id = Some(secret_id);
+        self.cache.bypassed = true;
+        self.read_rate_limit = RateLimitWindow::default();
+        self.write_rate_limit = RateLimitWindow::default();
+        Ok(())
+    }
+
+    // Back to the server's credentials after a scan with a per-query key
+    fn restore_server_credential(&mut self) {
+        if let Some(headers) = self.server_headers.take() {
+            self.headers = headers;
+            self.read_rate_limit = RateLimitWindow::default();
+            self.write_rate_limit = RateLimitWindow::default();
+        }
+        self.query_api_key_id = None;
+        self.cache.bypassed = false;
+    }
+
+    // The table's api_url if set, else the server's, for the statement about
+    // to run. One server can then mix production, staging and proxied tables.
+    fn use_table_api_url(&mut self, opts: &Options) -> FdwResult {
+        let base_url = opts.require_or("api_url", &self.server_base_url);
+        Self::validate_api_url(&base_url)?;
+        if self.debug && base_url != self.base_url {
+            self.log_info(&format!("[debug] Using api_url {}", self.redact(&base_url)));
+        }
+        self.cache.base_url = base_url.clone();
+        self.base_url = base_url;
+        Ok(())
+    }
+
+    // Likely misspellings of an option name: separators dropped or swapped.
+    // These are the only unknown options that can be detected.
+    fn option_typos(name: &str) -> Vec<String> {
+        let mut typos = vec![name.replace('_', "-"), name.replace('_', "")];
+        for (idx, _) in name.match_indices('_') {
+            typos.push(format!("{}{}", &name[..idx], &name[idx + 1..]));
+        }
+        typos.retain(|typo| typo != name);
+        typos.dedup();
+        typos
+    }
+
+    // The 0.1 options interface can't enumerate the options that were set, so
+    // only misspellings of known options are probed for; any other unknown
+    // option is silently ignored.
+    fn validate_option_names(opts: &Options, known: &[&str], kind: &str) -> FdwResult {
+        for name in known {
+            for typo in Self::option_typos(name) {
+                if !known.contains(&typo.as_str()) && opts.get(&typo).is_some() {
+                    return Err(format!(
+                        "Unknown {} option '{}'. Did you mean '{}'? Valid {} options are: {}. Other unknown options \
+                         can't be detected: the Wrappers 0.1 host doesn't list the options that were set.",
+                        kind,
+                        typo,
+                        name,
+                        kind,
+                        known.join(", ")
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Read a numeric option, rejecting values that don't parse
+    fn number_option<T: FromStr>(opts: &Options, key: &str, default: T) -> Result<T, FdwError> {
+        match opts.get(key) {
+            None => Ok(default),
+            Some(value) => value
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid value '{}' for option '{}'. Expected a number.", value, key)),
+        }
+    }
+
+    // Read an error_action option: 'error', 'skip' or 'row'
+    fn error_action_option(opts: &Options, key: &str, default: ErrorAction) -> Result<ErrorAction, FdwError> {
+        match opts.get(key) {
+            None => Ok(default),
+            Some(value) => ErrorAction::parse(value.trim())
+                .ok_or_else(|| format!("Invalid value '{}' for option '{}'. Expected 'error', 'skip' or 'row'.", value, key)),
+        }
+    }
+
+    // Read a boolean option, rejecting anything other than true/false
+    fn bool_option(opts: &Options, key: &str, default: bool) -> Result<bool, FdwError> {
+        match opts.get(key) {
+            None => Ok(default),
+            Some(value) => match value.to_lowercase().as_str() {
+                "true" => Ok(true),
+                "false" => Ok(false),
+                _ => Err(format!("Invalid value '{}' for option '{}'. Expected 'true' or 'false'.", value, key)),
+            },
+        }
+    }
+
+    // Stable, keyed identifier for an email that can't be reversed without the key
+    fn pseudonym(&self, email: &str) -> Option<String> {
+        let key = self.pseudonym_key.as_ref()?;
+        Some(hmac_sha256_hex(key.as_bytes(), email.trim().to_lowercase().as_bytes()))
+    }
+
+    // Build URL for gravatar profile
+    fn build_url(&self, hash: &str) -> String {
+        format!("{}/{}", self.base_url, hash)
+    }
+
+    // Case-insensitive lookup of a response header
+    fn header_value<'a>(resp: &'a http::Response, name: &str) -> Option<&'a str> {
+        find_header(&resp.headers, name)
+    }
+
+    // Request/trace ID returned by the API, if any
+    fn request_id(resp: &http::Response) -> Option<&str> {
+        Self::REQUEST_ID_HEADERS
+            .iter()
+            .find_map(|name| Self::header_value(resp, name))
+    }
+
+    // " (request id: ...)" suffix for messages, empty when the API didn't send one
+    fn request_id_suffix(resp: &http::Response) -> String {
+        Self::request_id(resp)
+            .map(|id| format!(" (request id: {})", id))
+            .unwrap_or_default()
+    }
+
+    // Register a credential value so it gets masked by `redact`
+    fn add_secret(&mut self, secret: &str) {
+        if !secret.is_empty() {
+            self.secrets.push(secret.to_owned());
+        }
+    }
+
+    // Mask every known credential in the given text, and with pii_safe_logging
+    // replace emails by their hash. All log output and error messages that
+    // may carry external data must go through here.
+    fn redact(&self, text: &str) -> String {
+        let text = self
+            .secrets
+            .iter()
+            .fold(text.to_owned(), |text, secret| text.replace(secret.as_str(), "<redacted>"));
+        self.email_hashes
+            .iter()
+            .fold(text, |text, (email, hash)| text.replace(email.as_str(), hash))
+    }
+
+    // Remember an email of the current statement so `redact` can hide it
+    fn track_email(&mut self, email: &str) {
+        if self.pii_safe_logging && !email.is_empty() {
+            self.email_hashes.insert(email.to_owned(), self.normalization.hash(email));
+        }
+    }
+
+    // Emails of the email conditions, registered for `redact` so messages
+    // quoting them, e.g. API error bodies, don't leak them
+    fn emails_from_quals(&mut self, quals: &[Qual]) -> Result<Vec<String>, FdwError> {
+        let emails = extract_emails(quals)?;
+        self.email_hashes.clear();
+        for email in &emails {
+            self.track_email(email);
+        }
+        Ok(emails)
+    }
+
+    // An email as log messages and errors show it: its profile hash with
+    // pii_safe_logging
+    fn log_email(&self, email: &str) -> String {
+        if self.pii_safe_logging {
+            self.normalization.hash(email)
+        } else {
+            email.to_owned()
+        }
+    }
+
+    // Derive a short scan ID so interleaved logs can be told apart
+    fn new_scan_id(&mut self, ctx: &Context) -> String {
+        self.scans_started += 1;
+        let mut hasher = Sha256::new();
+        hasher.update(time::epoch_secs().to_le_bytes());
+        hasher.update(self.scans_started.to_le_bytes());
+        for qual in ctx.get_quals() {
+            hasher.update(qual.deparse().as_bytes());
+        }
+        let digest = format!("{:x}", hasher.finalize());
+        digest[..8].to_string()
+    }
+
+    // Prefix messages with the current scan ID, if any, and mask secrets
+    fn log_message(&self, msg: &str) -> String {
+        match &self.scan_id {
+            Some(scan_id) => self.redact(&format!("[scan {}] {}", scan_id, msg)),
+            None => self.redact(msg),
+        }
+    }
+
+    fn log_info(&self, msg: &str) {
+        utils::report_info(&self.log_message(msg));
+    }
+
+    fn log_notice(&self, msg: &str) {
+        utils::report_notice(&self.log_message(msg));
+    }
+
+    fn log_warning(&self, msg: &str) {
+        utils::report_warning(&self.log_message(msg));
+    }
+
+    // Summarize the emails looked up since the previous progress notice.
+    // `batch_start` holds the (fetched, not found, failed) counts at that point.
+    fn log_batch_progress(&self, done: usize, total: usize, batch_start: &mut (usize, usize, usize)) {
+        let stats = &self.scan_stats;
+        let first = ((done - 1) / Self::NOTICE_BATCH_SIZE) * Self::NOTICE_BATCH_SIZE + 1;
+        self.log_info(&format!(
+            "Looked up emails {}-{} of {}: {} fetched, {} not found, {} failed",
+            first,
+            done,
+            total,
+            stats.fetched - batch_start.0,
+            stats.not_found - batch_start.1,
+            stats.failed - batch_start.2
+        ));
+        *batch_start = (stats.fetched, stats.not_found, stats.failed);
+    }
+
+    // Render request headers for debug output, hiding credentials
+    fn redacted_headers(&self, headers: &[(String, String)]) -> String {
+        let headers = headers
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.redact(&headers)
+    }
+
+    // Root of the Gravatar API, e.g. https://api.gravatar.com/v3
+    fn api_root(&self) -> &str {
+        let base_url = self.base_url.trim_end_matches('/');
+        base_url.strip_suffix("/profiles").unwrap_or(base_url)
+    }
+
+    fn is_authenticated(&self) -> bool {
+        self.headers.iter().any(|(key, _)| key.eq_ignore_ascii_case(&self.auth_header))
+    }
+
+    // Error message for an unexpected API response
+    fn api_error(&self, context: &str, resp: &http::Response) -> FdwError {
+        self.redact(&format!(
+            "{}: HTTP {}{}: {}",
+            context,
+            resp.status_code,
+            Self::request_id_suffix(resp),
+            resp.body
+        ))
+    }
+
+    fn method_name(method: http::Method) -> &'static str {
+        match method {
+            http::Method::Get => "GET",
+            http::Method::Post => "POST",
+            http::Method::Put => "PUT",
+            http::Method::Patch => "PATCH",
+            http::Method::Delete => "DELETE",
+        }
+    }
+
+    // Perform a GET request, logging it when debug mode is enabled.
+    // URLs only ever contain the email hash, so they are safe to log.
+    // Returns the response along with its round-trip time in seconds.
+    fn http_get(&mut self, url: String) -> Result<http::Response, FdwError> {
+        self.http_send(http::Method::Get, url, String::default())
+    }
+
+    // Keys from `api_keys` or the Vault secrets listed in `api_key_ids`
+    fn api_key_list(opts: &Options) -> Result<Option<Vec<String>>, FdwError> {
+        let split = |list: String| -> Vec<String> {
+            list.split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_owned)
+                .collect()
+        };
+        let api_keys = if let Some(list) = opts.get("api_keys") {
+            split(list)
+        } else if let Some(list) = opts.get("api_key_ids") {
+            split(list)
+                .into_iter()
+                .map(|secret_id| SecretSource::VaultId(secret_id).resolve("API key", &WasmHost))
+                .collect::<Result<_, _>>()?
+        } else {
+            return Ok(None);
+        };
+        if api_keys.is_empty() {
+            return Err("api_keys/api_key_ids must list at least one key".to_owned());
+        }
+        Ok(Some(api_keys))
+    }
+
+    // Value of the credential `name` from whichever source is configured
+    fn secret_option(opts: &Options, name: &str) -> Result<Option<String>, FdwError> {
+        SecretSource::from_options(opts, name)?
+            .map(|source| source.resolve(name, &WasmHost))
+            .transpose()
+    }
+
+    // Switch to the next configured API key. Its quota is separate, so the
+    // rate limit windows seen with the previous key no longer apply.
+    fn failover_api_key(&mut self) -> bool {
+        let Some(api_key) = self.api_keys.get(self.api_key_index + 1).cloned() else {
+            return false;
+        };
+        self.api_key_index += 1;
+        self.set_api_key(&api_key);
+        self.read_rate_limit = RateLimitWindow::default();
+        self.write_rate_limit = RateLimitWindow::default();
+        true
+    }
+
+    // Short, non-reversible fingerprint of the current credential, empty if none
+    fn credential_fingerprint(&self) -> String {
+        find_header(&self.headers, &self.auth_header)
+            .map(Self::fingerprint)
+            .unwrap_or_default()
+    }
+
+    // Fingerprint `api_key` would have once sent, see credential_fingerprint
+    fn key_fingerprint(&self, api_key: &str) -> String {
+        Self::fingerprint(&self.authorization_value(api_key))
+    }
+
+    fn fingerprint(authorization: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(authorization.as_bytes());
+        let digest = format!("{:x}", hasher.finalize());
+        digest[..16].to_string()
+    }
+
+    fn authorization_value(&self, api_key: &str) -> String {
+        if self.auth_scheme.is_empty() {
+            api_key.to_owned()
+        } else {
+            format!("{} {}", self.auth_scheme, api_key)
+        }
+    }
+
+    // Set the credential sent with every request, `Authorization: Bearer <key>`
+    // unless auth_header_name/auth_scheme say otherwise
+    fn set_api_key(&mut self, api_key: &str) {
+        self.add_secret(api_key);
+        let value = self.authorization_value(api_key);
+        self.headers.retain(|(key, _)| !key.eq_ignore_ascii_case(&self.auth_header));
+        self.headers.push((self.auth_header.clone(), value));
+    }
+
+    // Re-read the API key from Vault, e.g. after it was rotated. Returns
+    // whether a different key was found.
+    fn reload_vault_key(&mut self) -> bool {
+        let Some(source) = self.api_key_source.clone() else {
+            return false;
+        };
+        let vault_api_key = source.resolve("API key", &WasmHost).unwrap_or_default();
+        if vault_api_key.is_empty() || self.secrets.contains(&vault_api_key) {
+            return false;
+        }
+        self.set_api_key(&vault_api_key);
+        true
+    }
+
+    // Exchange the OAuth2 refresh token for a new access token. Returns
+    // whether a new token was installed.
+    fn refresh_oauth_token(&mut self) -> Result<bool, FdwError> {
+        // mock and demo modes never leave the instance
+        if self.mock_responses.is_some() || self.demo_mode {
+            return Ok(false);
+        }
+        let Some(oauth) = self.oauth.as_ref().filter(|oauth| !oauth.refresh_token.is_empty()) else {
+            return Ok(false);
+        };
+        let req = http::Request {
+            method: http::Method::Post,
+            url: oauth.token_url.clone(),
+            headers: vec![
+                ("accept".to_owned(), "application/json".to_owned()),
+                ("content-type".to_owned(), "application/x-www-form-urlencoded".to_owned()),
+            ],
+            body: oauth.form_body(),
+        };
+        let resp = http::post(&req).map_err(|e| self.redact(&format!("OAuth token refresh failed: {}", e)))?;
+        if resp.status_code != 200 {
+            return Err(self.api_error("OAuth token refresh failed", &resp));
+        }
+
+        let token: JsonValue = serde_json::from_str(&resp.body)
+            .map_err(|e| format!("OAuth token refresh returned an invalid response: {}", e))?;
+        let Some(access_token) = token.get("access_token").and_then(|v| v.as_str()) else {
+            return Err("OAuth token refresh response has no access_token".to_owned());
+        };
+        self.set_api_key(access_token);
+
+        let expires_at = token.get("expires_in").and_then(|v| v.as_i64()).map(|secs| time::epoch_secs() + secs);
+        // providers that rotate refresh tokens hand out a new one with every refresh
+        let rotated = token.get("refresh_token").and_then(|v| v.as_str()).map(str::to_owned);
+        if let Some(refresh_token) = &rotated {
+            self.add_secret(refresh_token);
+        }
+        if let Some(oauth) = self.oauth.as_mut() {
+            oauth.expires_at = expires_at;
+            if let Some(refresh_token) = rotated {
+                oauth.refresh_token = refresh_token;
+            }
+        }
+        self.log_info("OAuth access token refreshed");
+        Ok(true)
+    }
+
+    // Post the lifetime counters to `telemetry_url` once per interval. Best
+    // effort: failures are only logged and never affect the query.
+    fn report_telemetry(&mut self) {
+        let Some(url) = self.telemetry_url.clone() else {
+            return;
+        };
+        let now = time::epoch_secs();
+        // mock and demo modes never leave the instance
+        if self.mock_responses.is_some() || self.demo_mode {
+            return;
+        }
+        if now - self.lifetime.telemetry_sent_at < self.telemetry_interval_secs {
+            return;
+        }
+        self.lifetime.telemetry_sent_at = now;
+        let req = http::Request {
+            method: http::Method::Post,
+            url: url.clone(),
+            headers: vec![("content-type".to_owned(), "application/json".to_owned())],
+            body: self.lifetime.telemetry_payload(now).to_string(),
+        };
+        match http::post(&req) {
+            Ok(resp) if resp.status_code < 300 => {}
+            Ok(resp) => self.log_info(&format!("Telemetry report to {} failed with HTTP {}", url, resp.status_code)),
+            Err(err) => self.log_info(&format!("Telemetry report to {} failed: {}", url, err)),
+        }
+    }
+
+    // Perform a request with any method, see `http_get`. An OAuth access token
+    // known to be expired is refreshed first. A 401 is retried once with the
+    // key re-read from Vault or with a refreshed OAuth token. When several API
+    // keys are configured, a 401 or 429 fails over to the next key.
+    fn http_send(&mut self, method: http::Method, url: String, body: String) -> Result<http::Response, FdwError> {
+        self.http_send_with(method, url, body, &[])
+    }
+
+    // `http_send` with headers added to this request only, e.g. cache validators
+    fn http_send_with(
+        &mut self,
+        method: http::Method,
+        url: String,
+        body: String,
+        extra_headers: &[(String, String)],
+    ) -> Result<http::Response, FdwError> {
+        // A per-query credential is used as is: renewing or failing over would
+        // switch back to the server's credentials
+        let server_credential = self.query_api_key_id.is_none();
+        if server_credential && self.oauth.as_ref().is_some_and(|oauth| oauth.expired(time::epoch_secs())) {
+            self.refresh_oauth_token()?;
+        }
+        let mut resp = self.send_request(method, url.clone(), body.clone(), extra_headers)?;
+        if resp.status_code == 401 && server_credential {
+            if self.reload_vault_key() {
+                self.log_info("API key rejected (401), retrying with the key re-read from Vault");
+                resp = self.send_request(method, url.clone(), body.clone(), extra_headers)?;
+            } else if self.refresh_oauth_token()? {
+                self.log_info("OAuth access token rejected (401), retrying with a refreshed token");
+                resp = self.send_request(method, url.clone(), body.clone(), extra_headers)?;
+            }
+        }
+        while matches!(resp.status_code, 401 | 429) && server_credential && self.failover_api_key() {
+            self.log_warning(&format!(
+                "API key #{} {} (HTTP {}), failing over to key #{}",
+                self.api_key_index,
+                if resp.status_code == 401 { "rejected" } else { "rate limited" },
+                resp.status_code,
+                self.api_key_index + 1
+            ));
+            resp = self.send_request(method, url.clone(), body.clone(), extra_headers)?;
+        }
+        // auth_failure_action 'public': keep read paths working while the
+        // credential is being fixed, with the fields anyone can see
+        let read = matches!(method, http::Method::Get);
+        if resp.status_code == 401 && read && self.public_fallback && self.is_authenticated() {
+            if self.public_fallback_urls.is_empty() {
+                self.log_warning(&self.api_error(
+                    "Gravatar credentials rejected, falling back to unauthenticated requests returning public fields only",
+                    &resp,
+                ));
+            }
+            let headers = self.headers.clone();
+            self.headers.retain(|(key, _)| !key.eq_ignore_ascii_case(&self.auth_header));
+            let public = self.send_request(method, url.clone(), body, extra_headers);
+            self.headers = headers;
+            resp = public?;
+            self.public_fallback_urls.insert(url);
+            self.cache.frozen = true;
+        }
+        Ok(resp)
+    }
+
+    fn send_request(
+        &mut self,
+        method: http::Method,
+        url: String,
+        body: String,
+        extra_headers: &[(String, String)],
+    ) -> Result<http::Response, FdwError> {
+        let url = append_query_params(&url, &self.query_params);
+        let mut headers = self.headers.clone();
+        headers.extend_from_slice(extra_headers);
+        if !body.is_empty() {
+            headers.push(("content-type".to_owned(), "application/json".to_owned()));
+        }
+        let req = http::Request {
+            method,
+            url,
+            headers,
+            body,
+        };
+        let result = self.dispatch(req);
+
+        let quota = if matches!(method, http::Method::Get) { "read" } else { "write" };
+        self.key_usage
+            .entry((self.credential_fingerprint(), quota))
+            .or_default()
+            .record(result.as_ref().ok().map(|resp| resp.headers.as_slice()));
+        if let Ok(resp) = &result {
+            match method {
+                http::Method::Get => self.read_rate_limit.track(&resp.headers),
+                _ => self.write_rate_limit.track(&resp.headers),
+            }
+        }
+        result
+    }
+
+    // GET a public URL outside the API, e.g. an avatar image: no credential
+    // headers, no query_params, and no rate limit or key usage tracking
+    fn send_public_get(&mut self, url: String) -> Result<http::Response, FdwError> {
+        let req = http::Request {
+            method: http::Method::Get,
+            url,
+            headers: vec![("user-agent".to_owned(), Self::USER_AGENT.to_owned())],
+            body: String::default(),
+        };
+        self.dispatch(req)
+    }
+
+    // Send a request as built, or answer it from mock_responses or demo mode,
+    // logging it when debug mode is enabled
+    fn dispatch(&mut self, req: http::Request) -> Result<http::Response, FdwError> {
+        let method = req.method;
+        let method_name = Self::method_name(method);
+
+        if self.debug {
+            self.log_info(&format!(
+                "[debug] {} {} headers: {}",
+                method_name,
+                req.url,
+                self.redacted_headers(&req.headers)
+            ));
+        }
+
+        // Mocked and demo responses don't cost API quota
+        let live = self.mock_responses.is_none() && !self.demo_mode;
+        if live {
+            self.check_daily_budget()?;
+            self.daily_requests.record(time::epoch_secs());
+        }
+
+        let resp = match (&self.mock_responses, method) {
+            (Some(mocks), _) => Ok(Self::mock_response(mocks, &req.url)),
+            (None, _) if self.demo_mode => Ok(Self::demo_response(method, &req.url)),
+            (None, http::Method::Get) => http::get(&req),
+            (None, http::Method::Post) => http::post(&req),
+            (None, http::Method::Put) => http::put(&req),
+            (None, http::Method::Patch) => http::patch(&req),
+            (None, http::Method::Delete) => http::delete(&req),
+        };
+        self.scan_stats.record_request(&resp);
+        if let (Some(recording), Ok(resp)) = (self.recording.as_mut(), &resp) {
+            recording.insert(response_key(&req.url).to_owned(), Self::recorded_response(resp));
+        }
+        self.track_last_error(&req.url, &resp);
+
+        if self.debug {
+            match &resp {
+                Ok(resp) => self.log_info(&format!(
+                    "[debug] {} {} -> {} ({} bytes){}",
+                    method_name,
+                    req.url,
+                    resp.status_code,
+                    resp.body.len(),
+                    Self::request_id_suffix(resp)
+                )),
+                Err(err) => self.log_info(&format!(
+                    "[debug] {} {} -> failed: {}",
+                    method_name, req.url, err
+                )),
+            }
+        }
+
+        resp.map_err(|err| self.redact(&err))
+    }
+
+    // Canned response for `mock_responses`, keyed by `response_key`. An entry
+    // is either the payload returned with 200, or {"status": ..., "body": ...}
+    // with optional "headers". Unknown keys answer 404.
+    fn mock_response(mocks: &serde_json::Map<String, JsonValue>, url: &str) -> http::Response {
+        let mut headers = vec![("content-type".to_owned(), "application/json".to_owned())];
+        let (status_code, body) = match mocks.get(response_key(url)) {
+            Some(JsonValue::Object(entry)) if entry.get("status").is_some_and(|v| v.is_u64()) => {
+                let status_code = entry.get("status").and_then(|v| v.as_u64()).unwrap_or(200) as u16;
+                let body = match entry.get("body") {
+                    Some(JsonValue::String(body)) => body.clone(),
+                    Some(body) => body.to_string(),
+                    None => String::new(),
+                };
+                if let Some(JsonValue::Object(extra)) = entry.get("headers") {
+                    headers.extend(
+                        extra
+                            .iter()
+                            .filter_map(|(name, value)| value.as_str().map(|value| (name.clone(), value.to_owned()))),
+                    );
+                }
+                (status_code, body)
+            }
+            Some(payload) => (200, payload.to_string()),
+            None => (404, r#"{"error":"Profile not found","code":"not_found"}"#.to_owned()),
+        };
+        http::Response {
+            url: url.to_owned(),
+            status_code,
+            headers,
+            body,
+        }
+    }
+
+    // Response for `demo_mode`: profile lookups by hash get a synthetic
+    // profile, anything else answers 404 so writes are never applied.
+    fn demo_response(method: http::Method, url: &str) -> http::Response {
+        let hash = response_key(url);
+        let is_hash = hash.len() == 64 && hash.bytes().all(|b| b.is_ascii_hexdigit());
+        let (status_code, body) = match method {
+            http::Method::Get if is_hash => (200, demo_profile(hash).to_string()),
+            _ => (404, r#"{"error":"Not available in demo mode","code":"not_found"}"#.to_owned()),
+        };
+        http::Response {
+            url: url.to_owned(),
+            status_code,
+            headers: vec![("content-type".to_owned(), "application/json".to_owned())],
+            body,
+        }
+    }
+
+    // A live response in the `mock_responses` format. Only the headers the
+    // FDW reads are kept.
+    fn recorded_response(resp: &http::Response) -> JsonValue {
+        let headers: serde_json::Map<String, JsonValue> = resp
+            .headers
+            .iter()
+            .filter(|(name, _)| Self::is_read_header(name))
+            .map(|(name, value)| (name.to_lowercase(), JsonValue::String(value.clone())))
+            .collect();
+        serde_json::json!({
+            "status": resp.status_code,
+            "headers": headers,
+            "body": resp.body,
+        })
+    }
+
+    // Response headers with lowercased names, without cookies and with
+    // credentials redacted from the values
+    fn safe_response_headers(&self, headers: &[(String, String)]) -> serde_json::Map<String, JsonValue> {
+        headers
+            .iter()
+            .map(|(name, value)| (name.to_lowercase(), value))
+            .filter(|(name, _)| !Self::SECRET_RESPONSE_HEADERS.contains(&name.as_str()))
+            .map(|(name, value)| (name, JsonValue::String(self.redact(value))))
+            .collect()
+    }
+
+    // Response headers for the response_headers column
+    fn response_headers_json(&self, headers: &[(String, String)]) -> String {
+        JsonValue::Object(self.safe_response_headers(headers)).to_string()
+    }
+
+    // Response headers any column or log message reads
+    fn is_read_header(name: &str) -> bool {
+        let name = name.to_lowercase();
+        name.starts_with("x-ratelimit-")
+            || name == "etag"
+            || name == "last-modified"
+            || name == "content-language"
+            || Self::REQUEST_ID_HEADERS.contains(&name.as_str())
+    }
+
+    // Emit the responses recorded during the statement as a NOTICE
+    fn flush_recording(&mut self) {
+        let Some(recording) = self.recording.as_mut() else {
+            return;
+        };
+        if recording.is_empty() {
+            return;
+        }
+        let recording = JsonValue::Object(std::mem::take(recording));
+        self.log_notice(&format!("Gravatar recording (replay with mock_responses): {}", recording));
+    }
+
+    // Editable profile fields of an UPDATE on the 'me' table. NULLs are left
+    // untouched since columns not referenced by the statement may arrive as NULL.
+    fn profile_changes(row: &Row) -> Result<serde_json::Map<String, JsonValue>, FdwError> {
+        let mut changes = serde_json::Map::new();
+        for (col, cell) in row.cols().into_iter().zip(row_cells(row)) {
+            let col = column_field(&col);
+            if !Self::PROFILE_UPDATE_FIELDS.contains(&col.as_str()) {
+                continue;
+            }
+            match cell {
+                Some(Cell::String(value)) => {
+                    changes.insert(col, JsonValue::String(value));
+                }
+                Some(_) => return Err(format!("Column '{}' must be of type text", col)),
+                None => {}
+            }
+        }
+        Ok(changes)
+    }
+
+    // Send one buffered write to the API, returning the response status of
+    // Gravatar changes (prefetching only fills the local cache)
+    fn execute_write(&mut self, write: &PendingWrite) -> Result<Option<u16>, FdwError> {
+        let resp = match write {
+            PendingWrite::UpdateProfile { hash, changes } => {
+                let url = format!("{}/me/profile", self.api_root());
+                let body = JsonValue::Object(changes.clone()).to_string();
+                let resp = self.send_write(http::Method::Patch, url, body)?;
+                if resp.status_code != 200 {
+                    return Err(self.api_error(&format!("Failed to update profile '{}'", hash), &resp));
+                }
+                resp
+            }
+            // Makes the avatar the one shown for the authenticated profile hash
+            PendingWrite::SelectAvatar { image_id } => {
+                let hash = self.authenticated_hash()?;
+                let url = format!("{}/me/avatars/{}/email", self.api_root(), image_id);
+                let body = serde_json::json!({ "email_hash": hash }).to_string();
+                let resp = self.send_write(http::Method::Post, url, body)?;
+                if resp.status_code != 200 && resp.status_code != 204 {
+                    return Err(self.api_error(&format!("Failed to select avatar '{}'", image_id), &resp));
+                }
+                resp
+            }
+            PendingWrite::DeleteAvatar { image_id } => {
+                let url = format!("{}/me/avatars/{}", self.api_root(), image_id);
+                let resp = self.send_write(http::Method::Delete, url, String::default())?;
+                if resp.status_code != 200 && resp.status_code != 204 {
+                    return Err(self.api_error(&format!("Failed to delete avatar '{}'", image_id), &resp));
+                }
+                resp
+            }
+            PendingWrite::Prefetch { email } => {
+                self.prefetch(email)?;
+                return Ok(None);
+            }
+        };
+        Ok(Some(resp.status_code))
+    }
+
+    // Emit an audit notice for a change made to Gravatar through the FDW
+    fn audit_write(&self, write: &PendingWrite, result: &Result<Option<u16>, FdwError>) {
+        if matches!(write, PendingWrite::Prefetch { .. }) {
+            return;
+        }
+        let (status, error) = match result {
+            Ok(status) => (serde_json::json!(status), JsonValue::Null),
+            Err(err) => (JsonValue::Null, JsonValue::String(err.clone())),
+        };
+        let audit = serde_json::json!({
+            "operation": write.operation(),
+            "target": write.target(),
+            "changed_fields": write.changed_fields(),
+            "status": status,
+            "error": error,
+        });
+        self.log_notice(&format!("Gravatar audit: {}", audit));
+    }
+
+    // Send all buffered writes in order. The first failure aborts the rest;
+    // the error lists what was already applied, since that can't be undone.
+    fn flush_writes(&mut self) -> FdwResult {
+        let writes = std::mem::take(&mut self.pending_writes);
+        let total = writes.len();
+        let mut succeeded = Vec::new();
+
+        for (idx, write) in writes.iter().enumerate() {
+            let result = self.execute_write(write);
+            self.audit_write(write, &result);
+            if let Err(err) = result {
+                let applied = if succeeded.is_empty() {
+                    "none".to_owned()
+                } else {
+                    succeeded.join(", ")
+                };
+                return Err(self.redact(&format!(
+                    "Write {} of {} ({}) failed: {}. Already applied: {}. Not attempted: {}.",
+                    idx + 1,
+                    total,
+                    write.describe(),
+                    err,
+                    applied,
+                    total - idx - 1
+                )));
+            }
+            succeeded.push(write.describe());
+        }
+
+        Ok(())
+    }
+
+    // Profile hash of the authenticated user
+    fn authenticated_hash(&mut self) -> Result<String, FdwError> {
+        let url = format!("{}/me/profile", self.api_root());
+        let resp = self.http_get(url)?;
+        if resp.status_code != 200 {
+            return Err(self.api_error("Failed to fetch the authenticated profile", &resp));
+        }
+        let profile: JsonValue = serde_json::from_str(&resp.body)
+            .map_err(|e| self.redact(&format!("Failed to parse JSON response: {}", e)))?;
+        profile
+            .get("hash")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .ok_or_else(|| "The authenticated profile has no hash".to_owned())
+    }
+
+    // Whether the email passes allowed_domains and email_pattern. Disallowed
+    // emails are an error, or skipped with a warning when configured so.
+    fn email_allowed(&self, email: &str) -> Result<bool, FdwError> {
+        if !valid_email_syntax(email) {
+            let message = format!("'{}' is not a valid email address", self.log_email(email));
+            if !self.skip_invalid_emails {
+                return Err(self.redact(&message));
+            }
+            self.log_warning(&format!("{}, skipping it", message));
+            return Ok(false);
+        }
+        let message = if !self.domain_allowed(email) {
+            format!(
+                "Email {} is outside the allowed domains ({})",
+                self.log_email(email),
+                self.allowed_domains.join(", ")
+            )
+        } else if let Some(pattern) = self.email_pattern.as_ref().filter(|pattern| !pattern.is_match(email.trim())) {
+            format!(
+                "Email {} does not match email_pattern '{}'",
+                self.log_email(email),
+                Self::email_pattern_source(pattern)
+            )
+        } else {
+            return Ok(true);
+        };
+        if !self.skip_disallowed_emails {
+            return Err(self.redact(&message));
+        }
+        self.log_warning(&format!("{}, skipping it", message));
+        Ok(false)
+    }
+
+    fn domain_allowed(&self, email: &str) -> bool {
+        if self.allowed_domains.is_empty() {
+            return true;
+        }
+        let domain = email.trim().rsplit_once('@').map(|(_, domain)| domain.to_lowercase());
+        domain.is_some_and(|domain| self.allowed_domains.contains(&domain))
+    }
+
+    // email_pattern must match the whole email, so it is compiled anchored
+    fn compile_email_pattern(pattern: &str) -> Result<regex_lite::Regex, FdwError> {
+        regex_lite::Regex::new(&format!("^(?:{})$", pattern))
+            .map_err(|err| format!("Invalid value '{}' for option 'email_pattern': {}", pattern, err))
+    }
+
+    // The pattern as configured, without the anchors added when compiling it
+    fn email_pattern_source(pattern: &regex_lite::Regex) -> &str {
+        let source = pattern.as_str();
+        source.strip_prefix("^(?:").and_then(|s| s.strip_suffix(")$")).unwrap_or(source)
+    }
+
+    // Cheap authenticated request that fails fast on an invalid credential.
+    // Looking up a profile that doesn't exist answers 404 for a valid key.
+    fn verify_credentials(&mut self) -> FdwResult {
+        let url = self.build_url(&hash_email(""));
+        let resp = self.http_get(url)?;
+        if matches!(resp.status_code, 401 | 403) {
+            return Err(self.api_error("Gravatar credentials rejected, the API key is invalid or expired", &resp));
+        }
+        Ok(())
+    }
+
+    // Remember a failed request for the 'stats' table. 404 is the normal
+    // answer for emails without a profile, not an error.
+    fn track_last_error(&mut self, url: &str, resp: &Result<http::Response, FdwError>) {
+        let (status_code, message) = match resp {
+            Ok(resp) if resp.status_code < 400 || resp.status_code == 404 => return,
+            Ok(resp) => (Some(resp.status_code), self.api_error("Request failed", resp)),
+            Err(err) => (None, self.redact(err)),
+        };
+        let key = response_key(url);
+        let hash = (key.len() == 64 && key.bytes().all(|b| b.is_ascii_hexdigit())).then(|| key.to_owned());
+        self.lifetime.last_error = Some(LastError {
+            status_code,
+            message,
+            at: time::epoch_secs(),
+            hash,
+        });
+    }
+
+    // Restrict the avatar URLs of the scanned rows to `max_rating`, in the
+    // payload itself so the json and vcard columns agree. Only avatars table
+    // rows know their rating; profile avatars are left to Gravatar's `r`
+    // parameter, which serves the default image for a higher rated avatar.
+    fn apply_max_rating(&mut self) {
+        let Some(max_rating) = self.max_rating else {
+            return;
+        };
+        for scanned in &mut self.scanned_profiles {
+            let JsonValue::Object(ref mut map) = scanned.profile else {
+                continue;
+            };
+            let over_rated = map
+                .get("rating")
+                .and_then(|v| v.as_str())
+                .and_then(AvatarRating::parse)
+                .is_some_and(|rating| rating > max_rating);
+            for field in ["avatar_url", "image_url"] {
+                let Some(url) = map.get(field).and_then(|v| v.as_str()) else {
+                    continue;
+                };
+                let url = if over_rated && self.null_over_rated_avatars {
+                    JsonValue::Null
+                } else {
+                    JsonValue::String(max_rating.avatar_url(url))
+                };
+                map.insert(field.to_owned(), url);
+            }
+        }
+    }
+
+    // Point the avatar URLs of the scanned rows at `avatar_cdn_base`, in the
+    // payload like apply_max_rating (whose rating parameter is kept)
+    fn apply_avatar_cdn(&mut self) {
+        let Some(cdn_base) = &self.avatar_cdn_base else {
+            return;
+        };
+        for scanned in &mut self.scanned_profiles {
+            let JsonValue::Object(ref mut map) = scanned.profile else {
+                continue;
+            };
+            for field in ["avatar_url", "image_url"] {
+                if let Some(url) = map.get(field).and_then(|v| v.as_str()) {
+                    let url = cdn_avatar_url(url, cdn_base);
+                    map.insert(field.to_owned(), JsonValue::String(url));
+                }
+            }
+        }
+    }
+
+    // Quals the scan acts on and the ones left to Postgres, logged with
+    // `debug` to explain scans returning nothing or fetching more than expected
+    fn log_qual_pushdown(&self, table: &str, quals: &[Qual]) {
+        let (pushed, ignored): (Vec<&Qual>, Vec<&Qual>) = quals.iter().partition(|qual| qual_pushed_down(table, *qual));
+        let list = |quals: Vec<&Qual>| {
+            if quals.is_empty() {
+                "none".to_owned()
+            } else {
+                quals.into_iter().map(describe_qual).collect::<Vec<_>>().join(", ")
+            }
+        };
+        self.log_info(&format!(
+            "[debug] quals on {}: pushed down: {}; evaluated by Postgres only: {}",
+            table,
+            list(pushed),
+            list(ignored)
+        ));
+    }
+
+    // Planned cost of a profile scan, logged with `debug` before the first
+    // request. The host doesn't call the FDW for a plain EXPLAIN, so this is
+    // what EXPLAIN ANALYZE can show about API usage.
+    fn log_plan(&self, emails: &[String]) {
+        let now = time::epoch_secs();
+        let cached = emails
+            .iter()
+            .filter(|email| self.cache.get_within(&self.normalization.hash(email), now, self.max_age).is_some())
+            .count();
+        let uncached = emails.len() - cached;
+        let requests = if self.batch_size > 1 {
+            uncached.div_ceil(self.batch_size)
+        } else {
+            uncached
+        };
+        let cache_policy = if !self.cache.enabled() {
+            "cache disabled".to_owned()
+        } else {
+            match self.max_age {
+                Some(max_age) => format!("cache_ttl {} s, max_age {} s", self.cache.ttl_secs, max_age),
+                None => format!("cache_ttl {} s", self.cache.ttl_secs),
+            }
+        };
+        self.log_info(&format!(
+            "[debug] plan: {} emails, {} cached, {} expected requests before retries ({}, batch_size {})",
+            emails.len(),
+            cached,
+            requests,
+            cache_policy,
+            self.batch_size
+        ));
+    }
+
+    // Provenance of a row for the profile_source column: 'api', 'cache', or
+    // 'synthesized' for demo profiles and error rows, which carry no API data
+    fn profile_source(&self, scanned: &ScannedProfile) -> &'static str {
+        if scanned.error.is_some() || self.demo_mode {
+            "synthesized"
+        } else if scanned.fetch.from_cache {
+            "cache"
+        } else if self.public_fallback_urls.contains(&scanned.fetch.url) {
+            "public_fallback"
+        } else {
+            "api"
+        }
+    }
+
+    // Fields of a freshly fetched profile that differ from the cached copy it
+    // is about to replace, expired or not. The payloads are compared rather than
+    // the ETags, which may differ between API servers for the same content.
+    // Public fallback responses lack fields the cached copy has.
+    fn changes_since_cached(&self, hash: &str, profile: &JsonValue) -> Option<serde_json::Map<String, JsonValue>> {
+        if self.cache.frozen {
+            return None;
+        }
+        let previous = self.cache.get_expired(hash)?;
+        Some(profile_diff(&previous.profile, profile))
+    }
+
+    // Check a profile response against the bundled schema for
+    // `validate_responses`, warning with the paths that deviate from it
+    fn validate_profile(&self, subject: &str, profile: &JsonValue, request_id_suffix: &str) {
+        let Some(schema) = &self.profile_schema else {
+            return;
+        };
+        let violations = schema_violations(schema, profile);
+        if violations.is_empty() {
+            return;
+        }
+        let mut listed = violations.iter().take(Self::MAX_LISTED_VIOLATIONS).cloned().collect::<Vec<_>>().join("; ");
+        if violations.len() > Self::MAX_LISTED_VIOLATIONS {
+            listed.push_str(&format!("; and {} more", violations.len() - Self::MAX_LISTED_VIOLATIONS));
+        }
+        self.log_warning(&format!(
+            "Response for {} deviates from the profile schema{}: {}",
+            subject,
+            request_id_suffix,
+            listed
+        ));
+    }
+
+    // Fetch the profiles of several hashes with one request, for `batch_size`
+    // with batch_protocol 'hashes_query'. The proxy (the API does not offer
+    // batches) takes `?hashes=a,b` and answers with a JSON array of profiles
+    // carrying their `hash`; hashes missing from the array have no profile.
+    // Returns None when the endpoint doesn't support batches, so the caller
+    // falls back to single lookups.
+    fn fetch_batch(&mut self, hashes: &[String]) -> Result<Option<HashMap<String, ScannedProfile>>, FdwError> {
+        let url = format!("{}?hashes={}", self.base_url, hashes.join(","));
+        // A batch the endpoint rejects only probed for support: it doesn't
+        // count toward the daily budget, the scan stats or last_error
+        let before = (
+            self.daily_requests.clone(),
+            self.scan_stats.clone(),
+            self.lifetime.last_error.clone(),
+        );
+        let resp = self.http_get(url.clone())?;
+        match resp.status_code {
+            200 => {}
+            429 => return Err(self.rate_limit_error(&resp)),
+            401 => return Err(self.api_error("Authentication failed, the API key was rejected", &resp)),
+            _ => {
+                (self.daily_requests, self.scan_stats, self.lifetime.last_error) = before;
+                self.log_warning(&format!(
+                    "Batch lookups not supported by {} (HTTP {}), looking up profiles one by one",
+                    self.base_url, resp.status_code
+                ));
+                return Ok(None);
+            }
+        }
+        let Ok(JsonValue::Array(profiles)) = serde_json::from_str::<JsonValue>(&resp.body) else {
+            (self.daily_requests, self.scan_stats, self.lifetime.last_error) = before;
+            self.log_warning(&format!(
+                "Batch response is not a JSON array{}, looking up profiles one by one",
+                Self::request_id_suffix(&resp)
+            ));
+            return Ok(None);
+        };
+
+        let request_id_suffix = Self::request_id_suffix(&resp);
+        let fetch = FetchInfo {
+            url,
+            headers: resp.headers,
+            from_cache: false,
+            fetched_at: Some(time::epoch_secs()),
+            attempts: 1,
+            changed_fields: None,
+        };
+        let mut found = HashMap::new();
+        for profile in profiles {
+            let Some(hash) = profile.get("hash").and_then(|v| v.as_str()).map(str::to_owned) else {
+                continue;
+            };
+            if hashes.contains(&hash) {
+                self.schema_drift.observe(&profile);
+                self.validate_profile(&format!("profile {}", hash), &profile, &request_id_suffix);
+                let fetch = FetchInfo {
+                    changed_fields: self.changes_since_cached(&hash, &profile),
+                    ..fetch.clone()
+                };
+                self.cache.insert(hash.clone(), profile.clone(), fetch.headers.clone(), time::epoch_secs());
+                found.insert(hash, ScannedProfile::parsed(profile, fetch));
+            }
+        }
+        Ok(Some(found))
+    }
+
+    // One cheap request, the same as verify_credentials, for the 'health'
+    // table and `warmup`. Failures become the report instead of an error so
+    // monitoring jobs can alert on the status column.
+    fn health_check(&mut self) -> (JsonValue, FetchInfo) {
+        let url = self.build_url(&hash_email(""));
+        let (status, status_code, error, fetch) = match self.http_get(url.clone()) {
+            Ok(resp) => {
+                let status = match resp.status_code {
+                    200 | 404 => "ok",
+                    401 | 403 => "unauthorized",
+                    429 => "rate_limited",
+                    _ => "error",
+                };
+                let error = (status != "ok").then(|| self.api_error("Health check failed", &resp));
+                let fetch = FetchInfo {
+                    url,
+                    headers: resp.headers,
+                    from_cache: false,
+                    fetched_at: Some(time::epoch_secs()),
+                    attempts: 1,
+                    changed_fields: None,
+                };
+                (status, Some(resp.status_code), error, fetch)
+            }
+            Err(err) => {
+                let fetch = FetchInfo {
+                    url,
+                    attempts: 1,
+                    ..Default::default()
+                };
+                ("unreachable", None, Some(err), fetch)
+            }
+        };
+        let row = serde_json::json!({
+            "status": status,
+            "status_code": status_code,
+            "authenticated": self.is_authenticated() && status != "unauthorized",
+            "ratelimit_remaining": fetch.header_i64("x-ratelimit-remaining"),
+            "error": error,
+        });
+        (row, fetch)
+    }
+
+    fn scan_health(&mut self) {
+        let (row, fetch) = self.health_check();
+        self.scanned_profiles.push(ScannedProfile::parsed(row, fetch));
+    }
+
+    // Canary request made at init for `warmup`, failing when the API can't be
+    // reached or rejects the credential and reporting the status otherwise
+    fn warmup(&mut self) -> FdwResult {
+        let (report, _) = self.health_check();
+        let error = report["error"].as_str().unwrap_or_default();
+        match report["status"].as_str() {
+            Some("unreachable") => Err(format!("Warmup request to {} failed: {}", self.base_url, error)),
+            Some("unauthorized") => Err(format!("Warmup request rejected: {}", error)),
+            _ => {
+                utils::report_info(&format!(
+                    "Gravatar FDW warmup: {} reachable (HTTP {}), {}",
+                    self.base_url,
+                    report["status_code"],
+                    if self.is_authenticated() { "authenticated" } else { "public access" }
+                ));
+                Ok(())
+            }
+        }
+    }
+
+    // List the avatars of the authenticated user for the 'avatars' table
+    fn scan_avatars(&mut self) -> FdwResult {
+        if !self.is_authenticated() {
+            return Err("The 'avatars' table requires an API key (api_key or api_key_id)".to_owned());
+        }
+
+        let url = format!("{}/me/avatars", self.api_root());
+        let resp = self.http_get(url.clone())?;
+        if resp.status_code != 200 {
+            return Err(self.api_error("Failed to list avatars", &resp));
+        }
+
+        let avatars: Vec<JsonValue> = serde_json::from_str(&resp.body).map_err(|e| {
+            self.redact(&format!(
+                "Failed to parse JSON response: {}{}",
+                e,
+                Self::request_id_suffix(&resp)
+            ))
+        })?;
+        let fetch = FetchInfo {
+            url,
+            headers: resp.headers,
+            from_cache: false,
+            fetched_at: Some(time::epoch_secs()),
+            attempts: 1,
+            changed_fields: None,
+        };
+        for avatar in avatars {
+            self.scanned_profiles.push(ScannedProfile::parsed(avatar, fetch.clone()));
+            self.scan_stats.fetched += 1;
+        }
+
+        Ok(())
+    }
+
+    // Declared and inferred interests of the queried emails for the
+    // 'all_interests' table, one row per interest with its source. Declared
+    // interests come from the profile (cached if possible), inferred ones
+    // from the experimental inferred-interests endpoint.
+    fn scan_all_interests(&mut self, quals: &[Qual]) -> FdwResult {
+        let emails = self.emails_from_quals(quals)?;
+        if emails.is_empty() {
+            self.log_info("No email filters provided. The all_interests table requires email = '...' or email IN (...)");
+            return Ok(());
+        }
+        // A source condition spares the lookup of the other source
+        let sources = TextFilter::from_quals(quals, &["source"]);
+        let wanted = |source: &str| {
+            let row = serde_json::json!({ "source": source });
+            sources.iter().all(|filter| filter.matches(&row))
+        };
+        for email in emails {
+            if !self.email_allowed(&email)? {
+                continue;
+            }
+            let hash = self.normalization.hash(&email);
+            // only live lookups returning data count as fetched, cache hits are counted apart
+            let mut fetched = false;
+            let declared = if !wanted("declared") {
+                None
+            } else if let Some(entry) = self.cache.get(&hash, time::epoch_secs()) {
+                let profile = entry.profile.clone();
+                self.scan_stats.status.cache_hit += 1;
+                Some(profile)
+            } else {
+                let profile = self.fetch_json(&self.build_url(&hash), &email)?;
+                fetched |= profile.is_some();
+                profile
+            };
+            let declared = declared.and_then(|profile| profile.get("interests").cloned());
+            let inferred = if wanted("inferred") {
+                let interests = self.fetch_json(&self.inferred_interests_url(&hash), &email)?;
+                fetched |= interests.is_some();
+                interests
+            } else {
+                None
+            };
+            if fetched {
+                self.scan_stats.fetched += 1;
+            }
+
+            for (source, interests) in [("declared", declared), ("inferred", inferred)] {
+                let Some(JsonValue::Array(interests)) = interests else {
+                    continue;
+                };
+                for interest in interests {
+                    let row = serde_json::json!({
+                        "email": email,
+                        "hash": hash,
+                        "interest_id": interest.get("id"),
+                        "name": interest.get("name"),
+                        "source": source,
+                    });
+                    self.scanned_profiles.push(ScannedProfile::parsed(row, FetchInfo::default()));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn inferred_interests_url(&self, hash: &str) -> String {
+        format!("{}/inferred-interests", self.build_url(hash))
+    }
+
+    // Inferred interests of the profile rows, for the inferred_interests
+    // column. Each costs a request, so scans only call this when the column
+    // is selected.
+    fn add_inferred_interests(&mut self) -> FdwResult {
+        for idx in 0..self.scanned_profiles.len() {
+            let scanned = &self.scanned_profiles[idx];
+            if scanned.error.is_some() || scanned.parse_error() {
+                continue;
+            }
+            let Some(email) = scanned.profile.get("email").and_then(|v| v.as_str()).map(str::to_owned) else {
+                continue;
+            };
+            let url = self.inferred_interests_url(&self.normalization.hash(&email));
+            let Some(interests) = self.fetch_json(&url, &email)? else {
+                continue;
+            };
+            if let JsonValue::Object(ref mut map) = self.scanned_profiles[idx].profile {
+                map.insert("inferred_interests".to_owned(), interests);
+            }
+        }
+        Ok(())
+    }
+
+    // One row per link of the queried profiles. Conditions on label and url
+    // are applied here, so wide profiles only materialize the matching links.
+    fn scan_links(&mut self, quals: &[Qual]) -> FdwResult {
+        let emails = self.emails_from_quals(quals)?;
+        if emails.is_empty() {
+            self.log_info("No email filters provided. The links table requires email = '...' or email IN (...)");
+            return Ok(());
+        }
+        if !self.is_authenticated() {
+            self.log_warning("Profile links are only returned to authenticated requests. Set api_key or api_key_id.");
+        }
+        let filters = TextFilter::from_quals(quals, &["label", "url"]);
+        for email in emails {
+            if !self.email_allowed(&email)? {
+                continue;
+            }
+            let hash = self.normalization.hash(&email);
+            let profile = match self.cache.get(&hash, time::epoch_secs()) {
+                Some(entry) => {
+                    let profile = entry.profile.clone();
+                    self.scan_stats.status.cache_hit += 1;
+                    Some(profile)
+                }
+                None => {
+                    let profile = self.fetch_json(&self.build_url(&hash), &email)?;
+                    if profile.is_some() {
+                        self.scan_stats.fetched += 1;
+                    }
+                    profile
+                }
+            };
+            let Some(JsonValue::Array(links)) = profile.and_then(|profile| profile.get("links").cloned()) else {
+                continue;
+            };
+            for (idx, link) in links.iter().enumerate() {
+                let row = serde_json::json!({
+                    "email": email,
+                    "hash": hash,
+                    "position": idx + 1,
+                    "label": link.get("label"),
+                    "url": link.get("url"),
+                });
+                if filters.iter().all(|filter| filter.matches(&row)) {
+                    self.scanned_profiles.push(ScannedProfile::parsed(row, FetchInfo::default()));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Unparsed lookup responses for the 'profiles_raw' table: status, body
+    // and headers exactly as received, one row per email. Always a live
+    // request, bypassing cache, retries and mapping.
+    fn scan_profiles_raw(&mut self, quals: &[Qual]) -> FdwResult {
+        let emails = self.emails_from_quals(quals)?;
+        if emails.is_empty() {
+            self.log_info("No email filters provided. The profiles_raw table requires email = '...' or email IN (...)");
+            return Ok(());
+        }
+        for email in emails {
+            if !self.email_allowed(&email)? {
+                continue;
+            }
+            let hash = self.normalization.hash(&email);
+            let url = self.build_url(&hash);
+            let scanned = match self.http_get(url.clone()) {
+                Ok(resp) => {
+                    let headers = self.safe_response_headers(&resp.headers);
+                    let row = serde_json::json!({
+                        "email": email,
+                        "hash": hash,
+                        "status_code": resp.status_code,
+                        "body": resp.body,
+                        "headers": headers,
+                    });
+                    let fetch = FetchInfo {
+                        url,
+                        headers: resp.headers,
+                        from_cache: false,
+                        fetched_at: Some(time::epoch_secs()),
+                        attempts: 1,
+                        changed_fields: None,
+                    };
+                    ScannedProfile::parsed(row, fetch)
+                }
+                Err(err) => {
+                    let fetch = FetchInfo {
+                        url,
+                        attempts: 1,
+                        ..Default::default()
+                    };
+                    ScannedProfile::failed(&email, hash, err, fetch)
+                }
+            };
+            self.scanned_profiles.push(scanned);
+        }
+        Ok(())
+    }
+
+    // Whether the queried emails have a Gravatar, for tables with
+    // `exists_only`: one row per email with email, hash and exists. The
+    // host offers no HEAD requests, so each check fetches a 1px avatar with
+    // d=404 instead of the profile. A fresh cached profile answers without
+    // a request.
+    fn scan_exists(&mut self, quals: &[Qual]) -> FdwResult {
+        let emails = self.emails_from_quals(quals)?;
+        if emails.is_empty() {
+            self.log_info("No email filters provided. Existence checks require email = '...' or email IN (...)");
+            return Ok(());
+        }
+        for email in emails {
+            if !self.email_allowed(&email)? {
+                continue;
+            }
+            let hash = self.normalization.hash(&email);
+            let mut url = format!("{}/{}?d=404&s=1", avatar_base_url(&self.base_url), hash);
+            if let Some(cdn_base) = &self.avatar_cdn_base {
+                url = cdn_avatar_url(&url, cdn_base);
+            }
+            let (exists, fetch) = if let Some(entry) = self.cache.get(&hash, time::epoch_secs()) {
+                let fetch = FetchInfo {
+                    url,
+                    from_cache: true,
+                    fetched_at: Some(entry.fetched_at),
+                    ..Default::default()
+                };
+                (true, fetch)
+            } else {
+                self.check_daily_budget()?;
+                // The avatar host is public: no credentials or query_params
+                let (result, attempts) = self.with_read_retries(|this| this.send_public_get(url.clone()));
+                let mut fetch = FetchInfo {
+                    url,
+                    fetched_at: Some(time::epoch_secs()),
+                    attempts,
+                    ..Default::default()
+                };
+                let resp = match result {
+                    Ok(resp) => {
+                        fetch.headers = resp.headers.clone();
+                        resp
+                    }
+                    Err(err) if self.network_error_action == ErrorAction::Fail => return Err(err),
+                    Err(err) => {
+                        let message = format!("Existence check failed for email {}: {}", self.log_email(&email), err);
+                        self.log_warning(&message);
+                        self.scan_stats.failed += 1;
+                        if self.network_error_action == ErrorAction::Row {
+                            self.scanned_profiles.push(ScannedProfile::failed(&email, hash, err, fetch));
+                        }
+                        continue;
+                    }
+                };
+                let exists = match resp.status_code {
+                    200 => true,
+                    404 => false,
+                    _ => {
+                        let message = format!("Existence check failed for email {}", self.log_email(&email));
+                        if self.http_error_action == ErrorAction::Fail {
+                            return Err(self.api_error(&message, &resp));
+                        }
+                        let error = self.api_error(&message, &resp);
+                        self.log_warning(&error);
+                        self.scan_stats.failed += 1;
+                        if self.http_error_action == ErrorAction::Row {
+                            self.scanned_profiles.push(ScannedProfile::failed(&email, hash, error, fetch));
+                        }
+                        continue;
+                    }
+                };
+                (exists, fetch)
+            };
+            if exists {
+                self.scan_stats.fetched += 1;
+            } else {
+                self.scan_stats.not_found += 1;
+            }
+            let row = serde_json::json!({ "email": email, "hash": hash, "exists": exists });
+            self.scanned_profiles.push(ScannedProfile::parsed(row, fetch));
+        }
+        Ok(())
+    }
+
+    // GET a JSON document for a secondary lookup: None when the API has
+    // none (404) or answers with an error, which is only logged
+    fn fetch_json(&mut self, url: &str, email: &str) -> Result<Option<JsonValue>, FdwError> {
+        self.wait_for_rate_limit(false)?;
+        let resp = self.http_get(url.to_owned())?;
+        match resp.status_code {
+            200 => match serde_json::from_str(&resp.body) {
+                Ok(value) => Ok(Some(value)),
+                Err(e) => {
+                    self.log_warning(&format!(
+                        "Failed to parse JSON response for email {}{}: {}",
+                        self.log_email(email),
+                        Self::request_id_suffix(&resp),
+                        e
+                    ));
+                    Ok(None)
+                }
+            },
+            404 => Ok(None),
+            429 => Err(self.rate_limit_error(&resp)),
+            _ => {
+                self.log_warning(&self.api_error(&format!("Lookup failed for email {}", self.log_email(email)), &resp));
+                Ok(None)
+            }
+        }
+    }
+
+    // Recommended DDL of every table for the 'schema' table, no API call.
+    // The FDW can't see the server name, `gravatar_server` is the README default.
+    fn scan_schema(&mut self) {
+        for (table, columns) in TABLE_COLUMNS {
+            let row = serde_json::json!({
+                "table_name": table,
+                "ddl": recommended_ddl("gravatar", "gravatar_server", table, columns),
+            });
+            self.scanned_profiles.push(ScannedProfile::parsed(row, FetchInfo::default()));
+        }
+    }
+
+    // Repeat each fetched row for every queried email sharing its hash, in
+    // the order the emails were queried
+    fn fan_out_aliases(&mut self, queried_emails: &[String]) {
+        let normalization = &self.normalization;
+        let email_hash = |scanned: &ScannedProfile| {
+            scanned
+                .profile
+                .get("email")
+                .and_then(|v| v.as_str())
+                .map(|email| normalization.hash(email))
+                .unwrap_or_default()
+        };
+        let rows: HashMap<String, ScannedProfile> =
+            self.scanned_profiles.drain(..).map(|scanned| (email_hash(&scanned), scanned)).collect();
+        for email in queried_emails {
+            let Some(scanned) = rows.get(&normalization.hash(email)) else {
+                continue;
+            };
+            let mut scanned = scanned.clone();
+            if let JsonValue::Object(ref mut map) = scanned.profile {
+                map.insert("email".to_string(), JsonValue::String(email.clone()));
+            }
+            self.scanned_profiles.push(scanned);
+        }
+    }
+
+    // QR code image URLs for the 'qr' table. The images are PNGs, which the
+    // text-only host HTTP interface can't carry, so clients fetch the URLs.
+    // size, version and type come from quals, then table options.
+    fn scan_qr(&mut self, quals: &[Qual], opts: &Options) -> FdwResult {
+        let defaults = QrOptions::default();
+        let number = |field: &str, default: i64| -> Result<i64, FdwError> {
+            match pseudo_qual(quals, field) {
+                Some(Cell::I64(n)) => Ok(n),
+                Some(Cell::I32(n)) => Ok(n.into()),
+                Some(Cell::I16(n)) => Ok(n.into()),
+                Some(_) => Err(format!("Invalid {} condition. Expected an integer.", field)),
+                None => Self::number_option(opts, &format!("qr_{}", field), default),
+            }
+        };
+        let options = QrOptions {
+            size: number("size", defaults.size)?,
+            version: number("version", defaults.version)?,
+            kind: match pseudo_qual(quals, "type") {
+                Some(Cell::String(kind)) => kind,
+                Some(_) => return Err("Invalid type condition. Expected text.".to_owned()),
+                None => opts.require_or("qr_type", &defaults.kind),
+            },
+        };
+        options.validate()?;
+
+        let api_root = self.api_root().to_owned();
+        for email in self.emails_from_quals(quals)? {
+            if !self.email_allowed(&email)? {
+                continue;
+            }
+            let hash = self.normalization.hash(&email);
+            let row = serde_json::json!({
+                "email": email,
+                "qr_url": options.url(&api_root, &hash),
+                "hash": hash,
+                "size": options.size,
+                "version": options.version,
+                "type": options.kind,
+            });
+            self.scanned_profiles.push(ScannedProfile::parsed(row, FetchInfo::default()));
+        }
+        Ok(())
+    }
+
+    // Columns the profiles table can map, for the 'fields' table, no API call
+    fn scan_fields(&mut self) {
+        for (field_name, json_path, pg_type) in profile_fields() {
+            let row = serde_json::json!({
+                "field_name": field_name,
+                "derived": json_path.is_none(),
+                "json_path": json_path,
+                "pg_type": pg_type,
+            });
+            self.scanned_profiles.push(ScannedProfile::parsed(row, FetchInfo::default()));
+        }
+    }
+
+    // Build and configuration info for the 'version' table, no API call.
+    // The git hash is set by the release workflow, local builds have none.
+    fn scan_version(&mut self) {
+        let row = serde_json::json!({
+            "crate_name": env!("CARGO_PKG_NAME"),
+            "crate_version": env!("CARGO_PKG_VERSION"),
+            "git_hash": option_env!("GRAVATAR_FDW_GIT_HASH"),
+            "host_version_requirement": <Self as Guest>::host_version_requirement(),
+            "api_url": self.redact(&self.base_url),
+        });
+        self.scanned_profiles.push(ScannedProfile::parsed(row, FetchInfo::default()));
+    }
+
+    // Lifetime counters for the 'stats' table, no API call. The scan reading
+    // them is not counted yet, it is added when it ends like any other.
+    fn scan_lifetime_stats(&mut self) {
+        let stats = &self.lifetime;
+        let row = serde_json::json!({
+            "loaded_at": stats.loaded_at,
+            "scans": stats.scans,
+            "requests": stats.requests,
+            "bytes_received": stats.bytes_received,
+            "fetched": stats.fetched,
+            "not_found": stats.not_found,
+            "errors": stats.errors,
+            "cache_hits": stats.cache_hits,
+            "last_error_code": stats.last_error.as_ref().and_then(|error| error.status_code),
+            "last_error": stats.last_error.as_ref().map(|error| error.message.as_str()),
+            "last_error_at": stats.last_error.as_ref().map(|error| error.at),
+            "last_error_hash": stats.last_error.as_ref().and_then(|error| error.hash.as_deref()),
+        });
+        self.scanned_profiles.push(ScannedProfile::parsed(row, FetchInfo::default()));
+    }
+
+    // Requests and quota per credential for the 'usage' table, no API call.
+    // Credentials show as their fingerprint, or 'public' without one.
+    fn scan_usage(&mut self) {
+        let mut usage: Vec<_> = self.key_usage.iter().collect();
+        usage.sort_by(|a, b| a.0.cmp(b.0));
+        let fingerprints: Vec<String> = self.api_keys.iter().map(|key| self.key_fingerprint(key)).collect();
+        let rows: Vec<JsonValue> = usage
+            .into_iter()
+            .map(|((credential, quota), usage)| {
+                let key_number = fingerprints.iter().position(|fingerprint| fingerprint == credential).map(|idx| idx + 1);
+                serde_json::json!({
+                    "credential": if credential.is_empty() { "public" } else { credential.as_str() },
+                    "key_number": key_number,
+                    "quota": quota,
+                    "requests": usage.requests,
+                    "quota_limit": usage.limit,
+                    "quota_remaining": usage.remaining,
+                    "quota_used": usage.used(),
+                    "resets_at": usage.reset_at,
+                })
+            })
+            .collect();
+        for row in rows {
+            self.scanned_profiles.push(ScannedProfile::parsed(row, FetchInfo::default()));
+        }
+    }
+
+    // One row per cached profile, from every endpoint, oldest first
+    fn scan_cache_entries(&mut self) {
+        let now = time::epoch_secs();
+        let mut entries: Vec<_> = self.cache.entries.iter().collect();
+        entries.sort_by(|a, b| (a.1.fetched_at, a.0).cmp(&(b.1.fetched_at, b.0)));
+        let rows: Vec<(JsonValue, FetchInfo)> = entries
+            .into_iter()
+            .map(|(hash, entry)| {
+                let ttl_remaining = entry.fetched_at + self.cache.ttl_secs - now;
+                let row = serde_json::json!({
+                    "hash": hash,
+                    "api_url": self.redact(&entry.base_url),
+                    "status": if ttl_remaining > 0 { "fresh" } else { "expired" },
+                    "ttl_remaining": ttl_remaining.max(0),
+                    "etag": find_header(&entry.headers, "etag"),
+                    "entry": entry.to_json(hash),
+                });
+                let fetch = FetchInfo {
+                    fetched_at: Some(entry.fetched_at),
+                    from_cache: true,
+                    ..Default::default()
+                };
+                (row, fetch)
+            })
+            .collect();
+        for (row, fetch) in rows {
+            self.scanned_profiles.push(ScannedProfile::parsed(row, fetch));
+        }
+    }
+
+    // Pause until the read or write rate limit window resets when its quota is exhausted
+    fn wait_for_rate_limit(&mut self, write: bool) -> FdwResult {
+        let window = if write {
+            &mut self.write_rate_limit
+        } else {
+            &mut self.read_rate_limit
+        };
+        let Some(reset_at) = window.reset_at.take() else {
+            return Ok(());
+        };
+        let wait_secs = reset_at - time::epoch_secs();
+        if wait_secs > self.max_rate_limit_wait_secs {
+            return Err(format!("Rate limit quota exhausted. Wait {} seconds for reset.", wait_secs));
+        }
+        if wait_secs > 0 {
+            self.log_info(&format!("Rate limit quota exhausted, waiting {} seconds for reset", wait_secs));
+            time::sleep(wait_secs as u64 * 1000);
+        }
+        Ok(())
+    }
+
+    // Whether daily_request_budget is used up until midnight UTC
+    fn budget_exhausted(&self) -> bool {
+        self.daily_request_budget
+            .is_some_and(|budget| self.daily_requests.today(time::epoch_secs()) >= budget)
+    }
+
+    fn check_daily_budget(&self) -> FdwResult {
+        if self.budget_exhausted() {
+            return Err(self.budget_exhausted_error());
+        }
+        Ok(())
+    }
+
+    fn budget_exhausted_error(&self) -> FdwError {
+        format!(
+            "Daily request budget of {} requests exhausted. It resets at midnight UTC, in {} seconds.",
+            self.daily_request_budget.unwrap_or_default(),
+            DailyRequests::resets_in(time::epoch_secs())
+        )
+    }
+
+    // Send a write request, throttled by the write quota and retried
+    // according to the write retry policy
+    fn send_write(&mut self, method: http::Method, url: String, body: String) -> Result<http::Response, FdwError> {
+        let mut attempt = 0;
+        loop {
+            self.wait_for_rate_limit(true)?;
+            let resp = self.http_send(method, url.clone(), body.clone())?;
+            if !self.write_retry.should_retry(attempt, resp.status_code) {
+                return Ok(resp);
+            }
+            let delay_ms = self.write_retry.delay_ms(attempt);
+            attempt += 1;
+            self.log_info(&format!(
+                "Write request failed with HTTP {}, retrying in {} ms (attempt {} of {})",
+                resp.status_code, delay_ms, attempt, self.write_retry.max_retries
+            ));
+            time::sleep(delay_ms);
+        }
+    }
+
+    // Profile lookup retried after network failures and HTTP 5xx, each under
+    // its own policy. 429 is left to the rate limit handling. Also returns
+    // the number of requests made.
+    fn get_with_retries(
+        &mut self,
+        url: &str,
+        validators: &[(String, String)],
+    ) -> (Result<http::Response, FdwError>, u32) {
+        self.with_read_retries(|this| {
+            this.http_send_with(http::Method::Get, url.to_owned(), String::default(), validators)
+        })
+    }
+
+    // Run a read request, and again after network failures and HTTP 5xx
+    // like get_with_retries
+    fn with_read_retries(
+        &mut self,
+        mut send: impl FnMut(&mut Self) -> Result<http::Response, FdwError>,
+    ) -> (Result<http::Response, FdwError>, u32) {
+        let (mut network_attempt, mut http_attempt) = (0, 0);
+        loop {
+            let result = send(self);
+            let (reason, policy, attempt) = match &result {
+                Ok(resp) if resp.status_code >= 500 && http_attempt < self.http_retry.max_retries => {
+                    http_attempt += 1;
+                    (format!("HTTP {}", resp.status_code), &self.http_retry, http_attempt)
+                }
+                Err(err) if network_attempt < self.network_retry.max_retries && !self.budget_exhausted() => {
+                    network_attempt += 1;
+                    (err.clone(), &self.network_retry, network_attempt)
+                }
+                _ => return (result, 1 + network_attempt + http_attempt),
+            };
+            let delay_ms = policy.delay_ms(attempt - 1);
+            self.log_info(&format!(
+                "Lookup failed ({}), retrying in {} ms (attempt {} of {})",
+                reason, delay_ms, attempt, policy.max_retries
+            ));
+            time::sleep(delay_ms);
+        }
+    }
+
+    // With md5_fallback, a lookup that answered 404 is tried once more by the
+    // email's MD5 hash. Returns the fallback response, its URL and number of
+    // requests, or None when there was no fallback or it failed with a
+    // network error, which is only logged.
+    fn md5_fallback_lookup(
+        &mut self,
+        email: &str,
+        resp: &http::Response,
+    ) -> Option<(http::Response, String, u32)> {
+        if resp.status_code != 404 || !self.md5_fallback || self.budget_exhausted() {
+            return None;
+        }
+        let url = self.build_url(&self.normalization.md5_hash(email));
+        match self.get_with_retries(&url, &[]) {
+            (Ok(resp), attempts) => {
+                if resp.status_code == 200 {
+                    self.log_info(&format!("Profile for email {} found by its MD5 hash", self.log_email(email)));
+                }
+                Some((resp, url, attempts))
+            }
+            (Err(err), _) => {
+                self.log_warning(&format!("MD5 fallback lookup failed for email {}: {}", self.log_email(email), err));
+                None
+            }
+        }
+    }
+
+    // Fetch a profile into the cache, for INSERT into the 'prefetch' table
+    fn prefetch(&mut self, email: &str) -> FdwResult {
+        if !self.email_allowed(email)? {
+            return Ok(());
+        }
+        let hash = self.normalization.hash(email);
+        if self.cache.get(&hash, time::epoch_secs()).is_some() {
+            return Ok(());
+        }
+
+        self.wait_for_rate_limit(false)?;
+        let resp = self.http_get(self.build_url(&hash))?;
+        match resp.status_code {
+            200 => {
+                let profile: JsonValue = serde_json::from_str(&resp.body).map_err(|e| {
+                    self.redact(&format!(
+                        "Failed to parse JSON response: {}{}",
+                        e,
+                        Self::request_id_suffix(&resp)
+                    ))
+                })?;
+                self.cache.insert(hash, profile, resp.headers, time::epoch_secs());
+            }
+            404 => self.log_info(&format!("Profile not found for email: {}", self.log_email(email))),
+            429 => return Err(self.rate_limit_error(&resp)),
+            _ => self.log_warning(&format!(
+                "HTTP error {} for email {}{}: {}",
+                resp.status_code,
+                self.log_email(email),
+                Self::request_id_suffix(&resp),
+                resp.body
+            )),
+        }
 
-#[derive(Debug, Default)]
-struct GravatarFdw {
-    base_url: String,
-    headers: Vec<(String, String)>,
-    scanned_profiles: Vec<JsonValue>,
-    scan_index: usize,
-}
+        Ok(())
+    }
 
-// pointer for the static FDW instance
-static mut INSTANCE: *mut GravatarFdw = std::ptr::null_mut::<GravatarFdw>();
+    // Error message for a 429 response
+    fn rate_limit_error(&self, resp: &http::Response) -> FdwError {
+        // Check if we're using an API key
+        let using_api_key = self.is_authenticated();
 
-impl GravatarFdw {
-    const PROFILES_OBJECT: &'static str = "profiles";
+        // Build error message based on X-RateLimit-Reset header and API key usage
+        let mut error_msg = "Rate limit exceeded (429).".to_string();
 
-    // initialise FDW instance
-    fn init_instance() {
-        let instance = Self::default();
-        unsafe {
-            INSTANCE = Box::leak(Box::new(instance));
+        if let Some(reset_header) = Self::header_value(resp, "x-ratelimit-reset") {
+            if let Ok(reset_timestamp) = reset_header.parse::<u64>() {
+                let current_time = time::epoch_secs() as u64;
+                let wait_seconds = reset_timestamp.saturating_sub(current_time);
+                error_msg.push_str(&format!(" Wait {} seconds for reset.", wait_seconds));
+            }
+        }
+
+        if using_api_key {
+            error_msg.push_str(" Please contact Gravatar to increase your usage limit.");
+        } else {
+            error_msg.push_str(" Consider getting an API key at https://gravatar.com/developers/applications for higher rate limits.");
+        }
+
+        if let Some(request_id) = Self::request_id(resp) {
+            error_msg.push_str(&format!(" Request ID: {}.", request_id));
         }
+
+        error_msg
     }
 
-    fn this_mut() -> &'static mut Self {
-        unsafe { &mut (*INSTANCE) }
+    // Fetch the profile of the authenticated user for the 'me' table
+    fn scan_me(&mut self) -> FdwResult {
+        if !self.is_authenticated() {
+            return Err("The 'me' table requires an API key (api_key or api_key_id)".to_owned());
+        }
+
+        let url = format!("{}/me/profile", self.api_root());
+        let resp = self.http_get(url.clone())?;
+        if resp.status_code != 200 {
+            return Err(self.api_error("Failed to fetch the authenticated profile", &resp));
+        }
+
+        let profile: JsonValue = serde_json::from_str(&resp.body).map_err(|e| {
+            self.redact(&format!(
+                "Failed to parse JSON response: {}{}",
+                e,
+                Self::request_id_suffix(&resp)
+            ))
+        })?;
+        let fetch = FetchInfo {
+            url,
+            headers: resp.headers,
+            from_cache: false,
+            fetched_at: Some(time::epoch_secs()),
+            attempts: 1,
+            changed_fields: None,
+        };
+        self.scanned_profiles.push(ScannedProfile::parsed(profile, fetch));
+        self.scan_stats.fetched += 1;
+
+        Ok(())
     }
 
-    // Hash email using SHA-256
-    fn hash_email(email: &str) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(email.trim().to_lowercase().as_bytes());
-        format!("{:x}", hasher.finalize())
+    // Authenticated-only columns are NULL without an API key. Say so up front
+    // rather than returning them silently empty: 'profiles' warns, while
+    // 'profiles_public' rejects them and 'profiles_full' requires a key.
+    fn check_profile_access(&mut self, ctx: &Context, access: ProfileAccess) -> FdwResult {
+        if access == ProfileAccess::Full && !self.is_authenticated() {
+            return Err(format!(
+                "The '{}' table requires an API key (api_key or api_key_id). Use '{}' for public fields.",
+                Self::PROFILES_FULL_OBJECT,
+                Self::PROFILES_PUBLIC_OBJECT
+            ));
+        }
+        let authenticated: Vec<String> = ctx
+            .get_columns()
+            .iter()
+            .map(|col| column_field(&col.name()))
+            .filter(|name| AUTHENTICATED_FIELDS.contains(&name.as_str()))
+            .collect();
+        if authenticated.is_empty() {
+            return Ok(());
+        }
+        match access {
+            ProfileAccess::Public => Err(format!(
+                "Column(s) {} are not public profile fields and can't be queried on the '{}' table",
+                authenticated.join(", "),
+                Self::PROFILES_PUBLIC_OBJECT
+            )),
+            ProfileAccess::Any if !self.is_authenticated() => {
+                self.log_warning(&format!(
+                    "Column(s) {} are only returned to authenticated requests and will be NULL. Set api_key or \
+                     api_key_id, or use the '{}' table.",
+                    authenticated.join(", "),
+                    Self::PROFILES_PUBLIC_OBJECT
+                ));
+                Ok(())
+            }
+            _ => Ok(()),
+        }
     }
 
-    // Build URL for gravatar profile
-    fn build_url(&self, email: &str) -> String {
-        let hash = Self::hash_email(email);
-        format!("{}/{}", self.base_url, hash)
+    // Keeps the json and vcard columns of 'profiles_public' to public fields
+    // even when the server has an API key
+    fn strip_authenticated_fields(&mut self) {
+        for scanned in &mut self.scanned_profiles {
+            if let JsonValue::Object(ref mut map) = scanned.profile {
+                for field in AUTHENTICATED_FIELDS {
+                    map.remove(*field);
+                }
+            }
+            scanned.raw_body = None;
+        }
+    }
+
+    // Look up the profiles of the emails in the quals for the 'profiles' table
+    fn scan_profiles(&mut self, ctx: &Context, opts: &Options) -> FdwResult {
+        // Look for email filters in quals
+        let quals = ctx.get_quals();
+        let mut emails_to_fetch = self.emails_from_quals(&quals)?;
+        if let Some((list, emails)) = extract_email_list(&quals)? {
+            if !emails_to_fetch.is_empty() {
+                return Err("Use either an email or an emails condition, not both".to_owned());
+            }
+            emails_to_fetch = emails;
+            self.email_list = Some(list);
+        }
+
+        // A max_age qual overrides the table option for this scan
+        self.max_age = match extract_max_age(&quals)? {
+            Some(max_age) => Some(max_age),
+            None if opts.get("max_age").is_some() => Some(Self::number_option(opts, "max_age", 0)?),
+            None => None,
+        };
+
+        // If no email filter provided, we can't fetch profiles
+        if emails_to_fetch.is_empty() {
+            self.log_info(
+                "No email filter reached the FDW, so no profiles are looked up. Use WHERE email = '...' or email IN (...); \
+                 conditions Postgres keeps to itself, like OR between emails, return no rows",
+            );
+            return Ok(());
+        }
+
+        for email in &emails_to_fetch {
+            self.track_email(email);
+        }
+
+        // Enforce allowed_domains and email_pattern before any email reaches the API
+        let mut allowed_emails = Vec::with_capacity(emails_to_fetch.len());
+        for email in emails_to_fetch {
+            if self.email_allowed(&email)? {
+                allowed_emails.push(email);
+            }
+        }
+        let emails_to_fetch = allowed_emails;
+
+        // Emails differing only in case or surrounding spaces share a profile,
+        // which is fetched once. 'fan_out' returns it for each queried email,
+        // 'deduplicate' only for the first one.
+        let fan_out_aliases = match opts.require_or("email_aliases", "fan_out").as_str() {
+            "fan_out" => true,
+            "deduplicate" => false,
+            other => return Err(format!("Invalid value '{}' for option 'email_aliases'. Expected 'fan_out' or 'deduplicate'.", other)),
+        };
+        let mut seen_hashes = HashSet::new();
+        let unique_emails: Vec<String> = emails_to_fetch
+            .iter()
+            .filter(|email| seen_hashes.insert(self.normalization.hash(email)))
+            .cloned()
+            .collect();
+        let has_aliases = unique_emails.len() < emails_to_fetch.len();
+        let (queried_emails, mut emails_to_fetch) = (emails_to_fetch, unique_emails);
+
+        // Emails beyond max_rows could never be returned, so they aren't looked up
+        if let Some(max_rows) = self.max_rows.filter(|max_rows| emails_to_fetch.len() > *max_rows) {
+            self.log_warning(&format!(
+                "Looking up only the first {} of {} emails (max_rows)",
+                max_rows,
+                emails_to_fetch.len()
+            ));
+            emails_to_fetch.truncate(max_rows);
+        }
+
+        if self.debug {
+            self.log_plan(&emails_to_fetch);
+        }
+
+        // Small scans report every email; large ones report progress in batches
+        // so thousands of NOTICEs don't overwhelm the client
+        let total_emails = emails_to_fetch.len();
+        let batch_notices = total_emails > Self::NOTICE_BATCH_SIZE;
+        let mut batch_start = (0, 0, 0);
+
+        // With batch_size, fetch uncached profiles ahead in chunks. Hashes
+        // map to None when the batch had no profile for them.
+        let mut batched = HashMap::new();
+        if self.batch_size > 1 {
+            let now = time::epoch_secs();
+            let pending: Vec<String> = emails_to_fetch
+                .iter()
+                .map(|email| self.normalization.hash(email))
+                .filter(|hash| self.cache.get_within(hash, now, self.max_age).is_none())
+                .collect();
+            for chunk in pending.chunks(self.batch_size) {
+                if self.budget_exhausted() {
+                    break;
+                }
+                let Some(mut found) = self.fetch_batch(chunk)? else {
+                    break;
+                };
+                for hash in chunk {
+                    batched.insert(hash.clone(), found.remove(hash));
+                }
+            }
+        }
+
+        // Fetch profiles for each email. Rows are pushed in the order of the
+        // qual's email list, which callers rely on instead of an ORDER BY.
+        let mut budget_warned = false;
+        for (idx, email) in emails_to_fetch.into_iter().enumerate() {
+            if batch_notices && idx > 0 && idx % Self::NOTICE_BATCH_SIZE == 0 {
+                self.log_batch_progress(idx, total_emails, &mut batch_start);
+            }
+
+            let hash = self.normalization.hash(&email);
+
+            // Serve fresh cached profiles without calling the API
+            if let Some(entry) = self.cache.get_within(&hash, time::epoch_secs(), self.max_age) {
+                let mut profile = entry.profile.clone();
+                if let JsonValue::Object(ref mut map) = profile {
+                    map.insert("email".to_string(), JsonValue::String(email.clone()));
+                }
+                let fetch = FetchInfo {
+                    url: self.build_url(&hash),
+                    headers: entry.headers.clone(),
+                    from_cache: true,
+                    fetched_at: Some(entry.fetched_at),
+                    attempts: 0,
+                    changed_fields: None,
+                };
+                self.scanned_profiles.push(ScannedProfile::parsed(profile, fetch));
+                self.scan_stats.fetched += 1;
+                self.scan_stats.status.cache_hit += 1;
+                continue;
+            }
+
+            match batched.remove(&hash) {
+                Some(Some(mut scanned)) => {
+                    if let JsonValue::Object(ref mut map) = scanned.profile {
+                        map.insert("email".to_string(), JsonValue::String(email.clone()));
+                    }
+                    self.scanned_profiles.push(scanned);
+                    self.scan_stats.fetched += 1;
+                    continue;
+                }
+                Some(None) => {
+                    if !batch_notices {
+                        self.log_info(&format!("Profile not found for email: {}", self.log_email(&email)));
+                    }
+                    self.scan_stats.not_found += 1;
+                    continue;
+                }
+                None => {}
+            }
+
+            // Once daily_request_budget is used up, 'cache_only' serves expired
+            // cached profiles and leaves uncached emails out
+            if self.budget_exhausted() {
+                if !self.budget_cache_only {
+                    return Err(self.budget_exhausted_error());
+                }
+                if !budget_warned {
+                    budget_warned = true;
+                    self.log_warning(&format!("{} Serving cached profiles only.", self.budget_exhausted_error()));
+                }
+                if let Some(entry) = self.cache.get_expired(&hash) {
+                    let mut profile = entry.profile.clone();
+                    if let JsonValue::Object(ref mut map) = profile {
+                        map.insert("email".to_string(), JsonValue::String(email.clone()));
+                    }
+                    let fetch = FetchInfo {
+                        url: self.build_url(&hash),
+                        headers: entry.headers.clone(),
+                        from_cache: true,
+                        fetched_at: Some(entry.fetched_at),
+                        ..Default::default()
+                    };
+                    self.scanned_profiles.push(ScannedProfile::parsed(profile, fetch));
+                    self.scan_stats.fetched += 1;
+                    self.scan_stats.status.cache_hit += 1;
+                } else {
+                    self.scan_stats.failed += 1;
+                }
+                continue;
+            }
+
+            let url = self.build_url(&hash);
+            // An expired cache entry is revalidated rather than fetched again
+            let validators = self.cache.get_expired(&hash).map(CacheEntry::validators).unwrap_or_default();
+            let (result, attempts) = self.get_with_retries(&url, &validators);
+            let resp = match result {
+                Ok(resp) => resp,
+                Err(err) if self.network_error_action == ErrorAction::Fail => return Err(err),
+                Err(err) => {
+                    self.log_warning(&format!("Request failed for email {}: {}", self.log_email(&email), err));
+                    self.scan_stats.failed += 1;
+                    if self.network_error_action == ErrorAction::Row {
+                        let fetch = FetchInfo {
+                            url,
+                            attempts,
+                            ..Default::default()
+                        };
+                        self.scanned_profiles.push(ScannedProfile::failed(&email, hash, err, fetch));
+                    }
+                    continue;
+                }
+            };
+
+            // Some legacy accounts only resolve by the MD5 hash of the email
+            let (resp, url, attempts) = match self.md5_fallback_lookup(&email, &resp) {
+                Some((fallback, fallback_url, fallback_attempts)) => (fallback, fallback_url, attempts + fallback_attempts),
+                None => (resp, url, attempts),
+            };
+
+            // Handle 429 rate limiting
+            if resp.status_code == 429 {
+                return Err(self.rate_limit_error(&resp));
+            }
+
+            // The key is still rejected, even after re-reading it from Vault
+            if resp.status_code == 401 {
+                return Err(self.api_error("Authentication failed, the API key was rejected", &resp));
+            }
+
+            // Not modified since the cached copy, which counts as a cache hit
+            if resp.status_code == 304 {
+                if let Some(entry) = self.cache.revalidate(&hash, resp.headers.clone(), time::epoch_secs()) {
+                    let mut profile = entry.profile.clone();
+                    if let JsonValue::Object(ref mut map) = profile {
+                        map.insert("email".to_string(), JsonValue::String(email.clone()));
+                    }
+                    let fetch = FetchInfo {
+                        url,
+                        headers: entry.headers.clone(),
+                        from_cache: true,
+                        fetched_at: Some(entry.fetched_at),
+                        attempts,
+                        changed_fields: Some(serde_json::Map::new()),
+                    };
+                    self.scanned_profiles.push(ScannedProfile::parsed(profile, fetch));
+                    self.scan_stats.fetched += 1;
+                    self.scan_stats.status.cache_hit += 1;
+                    continue;
+                }
+            }
+
+            if resp.status_code == 200 {
+                let mut fetch = FetchInfo {
+                    url,
+                    headers: resp.headers.clone(),
+                    from_cache: false,
+                    fetched_at: Some(time::epoch_secs()),
+                    attempts,
+                    changed_fields: None,
+                };
+
+                // Parse successful response. A malformed body doesn't abort the scan:
+                // the email still gets a row carrying the raw body and a parse_error flag.
+                match serde_json::from_str::<JsonValue>(&resp.body) {
+                    Ok(mut profile) => {
+                        self.schema_drift.observe(&profile);
+                        let subject = format!("email {}", self.log_email(&email));
+                        self.validate_profile(&subject, &profile, &Self::request_id_suffix(&resp));
+                        fetch.changed_fields = self.changes_since_cached(&hash, &profile);
+                        self.cache.insert(hash, profile.clone(), resp.headers.clone(), time::epoch_secs());
+
+                        // Add email to the response since API doesn't return it
+                        if let JsonValue::Object(ref mut map) = profile {
+                            map.insert("email".to_string(), JsonValue::String(email.clone()));
+                        }
+
+                        self.scanned_profiles.push(ScannedProfile::parsed(profile, fetch));
+                        self.scan_stats.fetched += 1;
+                    }
+                    Err(e) => {
+                        self.log_warning(&format!(
+                            "Failed to parse JSON response for email {}{}: {}",
+                            self.log_email(&email),
+                            Self::request_id_suffix(&resp),
+                            e
+                        ));
+                        self.scanned_profiles.push(ScannedProfile::unparsable(&email, hash, resp.body, fetch));
+                        self.scan_stats.failed += 1;
+                    }
+                }
+            } else {
+                // Handle 404 (expected for private or non-existing profiles) and generic API errors
+                // by skipping this email - no row will be returned for failed lookups
+                if resp.status_code == 404 {
+                    if !batch_notices {
+                        self.log_info(&format!("Profile not found for email: {}", self.log_email(&email)));
+                    }
+                    self.scan_stats.not_found += 1;
+                } else if self.http_error_action == ErrorAction::Fail {
+                    return Err(self.api_error(&format!("Lookup failed for email {}", self.log_email(&email)), &resp));
+                } else {
+                    self.log_warning(&format!(
+                        "HTTP error {} for email {}{}: {}",
+                        resp.status_code,
+                        self.log_email(&email),
+                        Self::request_id_suffix(&resp),
+                        resp.body
+                    ));
+                    self.scan_stats.failed += 1;
+                    if self.http_error_action == ErrorAction::Row {
+                        let error = self.api_error("Lookup failed", &resp);
+                        let fetch = FetchInfo {
+                            url,
+                            headers: resp.headers,
+                            from_cache: false,
+                            fetched_at: Some(time::epoch_secs()),
+                            attempts,
+                            changed_fields: None,
+                        };
+                        self.scanned_profiles.push(ScannedProfile::failed(&email, hash, error, fetch));
+                    }
+                }
+            }
+        }
+
+        if batch_notices {
+            self.log_batch_progress(total_emails, total_emails, &mut batch_start);
+        }
+
+        if self.fetch_inferred_interests {
+            self.add_inferred_interests()?;
+        }
+
+        if has_aliases && fan_out_aliases {
+            self.fan_out_aliases(&queried_emails);
+        }
+
+        // Emit rows already in ORDER BY order. The sort is stable, so ties
+        // keep the IN list order.
+        let sorts: Vec<SortKey> = ctx
+            .get_sorts()
+            .iter()
+            .map(|sort| SortKey {
+                field: column_field(&sort.field()),
+                reversed: sort.reversed(),
+                nulls_first: sort.nulls_first(),
+            })
+            .collect();
+        if !sorts.is_empty() {
+            self.scanned_profiles.sort_by(|a, b| compare_profiles(&sorts, &a.profile, &b.profile));
+        }
+
+        Ok(())
     }
 }
 
@@ -69,146 +3354,347 @@ impl Guest for GravatarFdw {
         let this = Self::this_mut();
 
         let opts = ctx.get_options(OptionsType::Server);
-        this.base_url = opts.require_or("api_url", "https://api.gravatar.com/v3/profiles");
+        Self::validate_option_names(&opts, Self::SERVER_OPTIONS, "server")?;
+
+        let env_name = opts.require_or("environment", "production");
+        let Some(environment) = Environment::parse(&env_name) else {
+            return Err(format!("Invalid value '{}' for option 'environment'. Expected 'production' or 'staging'.", env_name));
+        };
+        this.base_url = opts.require_or("api_url", environment.base_url());
+        this.max_rate_limit_wait_secs = environment.max_rate_limit_wait_secs();
+        Self::validate_api_url(&this.base_url)?;
+        this.server_base_url = this.base_url.clone();
+        this.cache.base_url = this.base_url.clone();
+        this.debug = Self::bool_option(&opts, "debug", false)?;
+        this.cache.ttl_secs = Self::number_option(&opts, "cache_ttl", 0)?;
+        this.batch_size = Self::number_option(&opts, "batch_size", 1)?;
+        if this.batch_size == 0 {
+            return Err("Invalid value '0' for option 'batch_size'. Expected at least 1.".to_owned());
+        }
+        // The Gravatar API has no batch endpoint, batches need a proxy
+        // speaking a protocol the FDW knows
+        match opts.get("batch_protocol").as_deref() {
+            Some("hashes_query") => {}
+            Some(other) => {
+                return Err(format!(
+                    "Invalid value '{}' for option 'batch_protocol'. Expected 'hashes_query'.",
+                    other
+                ))
+            }
+            None if this.batch_size > 1 => {
+                return Err("Option 'batch_size' above 1 requires batch_protocol 'hashes_query': the Gravatar API \
+                            has no batch endpoint, only a proxy answering GET <api_url>?hashes=... can serve batches."
+                    .to_owned())
+            }
+            None => {}
+        }
+        this.allowed_domains = opts
+            .get("allowed_domains")
+            .map(|list| {
+                list.split(',')
+                    .map(|domain| domain.trim().trim_start_matches('@').to_lowercase())
+                    .filter(|domain| !domain.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        this.email_pattern = match opts.get("email_pattern") {
+            Some(pattern) => Some(Self::compile_email_pattern(&pattern)?),
+            None => None,
+        };
+        this.skip_disallowed_emails = match opts.require_or("disallowed_email_action", "error").as_str() {
+            "error" => false,
+            "skip" => true,
+            other => return Err(format!("Invalid value '{}' for option 'disallowed_email_action'. Expected 'error' or 'skip'.", other)),
+        };
+        this.skip_invalid_emails = match opts.require_or("invalid_email_action", "skip").as_str() {
+            "error" => false,
+            "skip" => true,
+            other => return Err(format!("Invalid value '{}' for option 'invalid_email_action'. Expected 'error' or 'skip'.", other)),
+        };
+        this.pii_safe_logging = Self::bool_option(&opts, "pii_safe_logging", false)?;
+        this.avatar_cdn_base = match opts.get("avatar_cdn_base") {
+            Some(base) if base.starts_with("https://") || base.starts_with("http://") => Some(base),
+            Some(base) => {
+                return Err(format!("Invalid value '{}' for option 'avatar_cdn_base'. Expected an http:// or https:// URL.", base))
+            }
+            None => None,
+        };
+        if let Some(params) = opts.get("query_params") {
+            this.query_params = parse_query_params(&params).ok_or_else(|| {
+                format!(
+                    "Invalid value '{}' for option 'query_params'. \
+                     Expected a JSON object of text, number or boolean values.",
+                    params
+                )
+            })?;
+        }
+        this.md5_fallback = Self::bool_option(&opts, "md5_fallback", false)?;
+        this.server_label = opts.get("server_label");
+        this.json_metrics = match opts.require_or("scan_metrics_format", "text").as_str() {
+            "text" => false,
+            "json" => true,
+            other => return Err(format!("Invalid value '{}' for option 'scan_metrics_format'. Expected 'text' or 'json'.", other)),
+        };
+        let idn_policy = opts.require_or("idn_policy", "preserve");
+        this.network_error_action = Self::error_action_option(&opts, "network_error_action", ErrorAction::Fail)?;
+        this.http_error_action = Self::error_action_option(&opts, "http_error_action", ErrorAction::Skip)?;
+        let read_backoff_ms = Self::number_option(&opts, "read_retry_backoff_ms", 1000)?;
+        let retry_schedule = match opts.get("retry_schedule") {
+            Some(value) => parse_retry_schedule(&value).filter(|delays| !delays.is_empty()).ok_or_else(|| {
+                format!("Invalid value '{}' for option 'retry_schedule'. Expected delays like '1s,5s,30s'.", value)
+            })?,
+            None => Vec::new(),
+        };
+        // An explicit schedule also retries as many times as it has delays,
+        // unless the max_retries options say otherwise
+        let scheduled_retries = retry_schedule.len() as u32;
+        this.network_retry = RetryPolicy {
+            max_retries: Self::number_option(&opts, "network_max_retries", scheduled_retries)?,
+            backoff_ms: read_backoff_ms,
+            schedule: retry_schedule.clone(),
+        };
+        this.http_retry = RetryPolicy {
+            max_retries: Self::number_option(&opts, "http_max_retries", scheduled_retries)?,
+            backoff_ms: read_backoff_ms,
+            schedule: retry_schedule.clone(),
+        };
+        this.normalization = EmailNormalization {
+            strip_plus_tags: Self::bool_option(&opts, "strip_plus_tags", false)?,
+            idn: IdnPolicy::parse(&idn_policy).ok_or_else(|| {
+                format!("Invalid value '{}' for option 'idn_policy'. Expected 'preserve', 'nfc' or 'punycode'.", idn_policy)
+            })?,
+        };
+        if let Some(mocks) = opts.get("mock_responses") {
+            match serde_json::from_str::<JsonValue>(&mocks) {
+                Ok(JsonValue::Object(mocks)) => this.mock_responses = Some(mocks),
+                _ => return Err("Invalid value for option 'mock_responses'. Expected a JSON object mapping hashes to responses.".to_owned()),
+            }
+            utils::report_info("Gravatar FDW serving mock responses, no requests are sent to the API");
+        } else if Self::bool_option(&opts, "demo_mode", false)? {
+            this.demo_mode = true;
+            utils::report_info("Gravatar FDW in demo mode, profiles are synthetic and no requests are sent to the API");
+        } else if Self::bool_option(&opts, "record_responses", false)? {
+            this.recording = Some(serde_json::Map::new());
+        }
+        this.profile_schema = if Self::bool_option(&opts, "validate_responses", false)? {
+            Some(serde_json::from_str(PROFILE_SCHEMA).map_err(|e| format!("Bundled profile schema is invalid: {}", e))?)
+        } else {
+            None
+        };
+        this.telemetry_url = opts.get("telemetry_url").filter(|url| !url.trim().is_empty());
+        this.telemetry_interval_secs = Self::number_option(&opts, "telemetry_interval", 86400)?;
+        this.pseudonym_key = Self::secret_option(&opts, "pseudonym_key")?;
+        if let Some(pseudonym_key) = this.pseudonym_key.clone() {
+            this.add_secret(&pseudonym_key);
+        }
+        let daily_request_budget = Self::number_option(&opts, "daily_request_budget", 0)?;
+        this.daily_request_budget = Some(daily_request_budget).filter(|budget| *budget > 0);
+        this.budget_cache_only = match opts.require_or("budget_exceeded_action", "error").as_str() {
+            "error" => false,
+            "cache_only" => true,
+            other => {
+                return Err(format!(
+                    "Invalid value '{}' for option 'budget_exceeded_action'. Expected 'error' or 'cache_only'.",
+                    other
+                ))
+            }
+        };
+        let write_retry = environment.write_retry();
+        this.write_retry = RetryPolicy {
+            max_retries: Self::number_option(
+                &opts,
+                "write_max_retries",
+                if retry_schedule.is_empty() { write_retry.max_retries } else { scheduled_retries },
+            )?,
+            backoff_ms: Self::number_option(&opts, "write_retry_backoff_ms", write_retry.backoff_ms)?,
+            schedule: retry_schedule,
+        };
 
         // Initialize basic headers
-        let user_agent = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
-        this.headers.push(("user-agent".to_owned(), user_agent));
+        this.headers.push(("user-agent".to_owned(), Self::USER_AGENT.to_owned()));
         this.headers.push(("accept".to_owned(), "application/json".to_owned()));
 
+        // Proxies may expect the credential in another header, e.g. X-Api-Key
+        this.auth_header = opts.require_or("auth_header_name", "authorization").trim().to_lowercase();
+        if this.auth_header.is_empty() || !this.auth_header.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(format!("Invalid value '{}' for option 'auth_header_name'. Expected an HTTP header name.", this.auth_header));
+        }
+        this.auth_scheme = opts.require_or("auth_scheme", "Bearer").trim().to_owned();
+        this.public_fallback = match opts.require_or("auth_failure_action", "error").as_str() {
+            "error" => false,
+            "public" => true,
+            other => return Err(format!("Invalid value '{}' for option 'auth_failure_action'. Expected 'error' or 'public'.", other)),
+        };
+
         // Handle API key authentication
-        // Support two options: direct api_key or api_key_id (vault UUID)
-        if let Some(api_key) = opts.get("api_key") {
-            // Direct API key provided (not recommended for production)
-            this.headers.push(("authorization".to_owned(), format!("Bearer {}", api_key)));
-            utils::report_info("Gravatar FDW initialized with direct API key");
-        } else if let Some(api_key_id) = opts.get("api_key_id") {
-            // Get API key from Vault using UUID
-            let vault_api_key = utils::get_vault_secret(&api_key_id).unwrap_or_default();
-            if !vault_api_key.is_empty() {
-                this.headers.push(("authorization".to_owned(), format!("Bearer {}", vault_api_key)));
+        // Support direct api_key or api_key_id (vault UUID)
+        // Several keys for failover: api_keys or api_key_ids, comma-separated
+        if let Some(api_keys) = Self::api_key_list(&opts)? {
+            this.set_api_key(&api_keys[0]);
+            utils::report_info(&format!("Gravatar FDW initialized with {} API keys for failover", api_keys.len()));
+            this.api_keys = api_keys;
+        } else if let Some(source) = SecretSource::from_options(&opts, "api_key")? {
+            // A direct api_key is not recommended for production
+            this.set_api_key(&source.resolve("API key", &WasmHost)?);
+            if source.is_vault() {
                 utils::report_info("Gravatar FDW initialized with API key from Vault");
+                this.api_key_source = Some(source);
             } else {
-                return Err(format!("Failed to retrieve API key from Vault using ID: {}", api_key_id));
+                utils::report_info("Gravatar FDW initialized with direct API key");
             }
+        } else if let Some(access_token) = Self::secret_option(&opts, "oauth_access_token")? {
+            // OAuth2 access token, renewed with the refresh token when it expires
+            this.set_api_key(&access_token);
+            if let Some(refresh_token) = Self::secret_option(&opts, "oauth_refresh_token")? {
+                let client_secret = Self::secret_option(&opts, "oauth_client_secret")?.unwrap_or_default();
+                this.add_secret(&refresh_token);
+                this.add_secret(&client_secret);
+                this.oauth = Some(OAuthRefresh {
+                    token_url: opts.require_or("oauth_token_url", "https://public-api.wordpress.com/oauth2/token"),
+                    client_id: opts.get("oauth_client_id").unwrap_or_default(),
+                    client_secret,
+                    refresh_token,
+                    expires_at: None,
+                });
+            }
+            utils::report_info("Gravatar FDW initialized with OAuth access token");
         } else {
             // No API key provided - will use public API endpoints only
             utils::report_info("Gravatar FDW initialized without API key (public access only)");
         }
 
-        utils::report_info(&format!("Gravatar FDW initialized with base URL: {}", this.base_url));
+        // ALTER SERVER can change the credential between instances
+        let credential = this.credential_fingerprint();
+        if this.cache.credential != credential {
+            this.cache.entries.clear();
+            this.cache.credential = credential.clone();
+        }
+        // checked once per credential rather than on every re-initialization
+        if Self::bool_option(&opts, "verify_credentials", false)?
+            && this.is_authenticated()
+            && this.verified_credential != credential
+        {
+            this.verify_credentials()?;
+            this.verified_credential = this.credential_fingerprint();
+        }
+        if let Some(serialized) = opts.get("cache_preload") {
+            if !this.cache.enabled() {
+                return Err("The cache_preload option requires caching. Set the cache_ttl server option.".to_owned());
+            }
+            let added = this
+                .cache
+                .preload(&serialized)
+                .map_err(|err| format!("Invalid value for option 'cache_preload': {}", err))?;
+            if added > 0 {
+                this.log_info(&format!("Preloaded {} cache entries", added));
+            }
+        }
+        let warmup_key = format!("{} {}", this.base_url, this.credential_fingerprint());
+        if Self::bool_option(&opts, "warmup", false)? && this.warmed_up != warmup_key {
+            this.warmup()?;
+            this.warmed_up = warmup_key;
+        }
+
+        this.log_info(&format!("Gravatar FDW initialized with base URL: {}", this.base_url));
 
         Ok(())
     }
 
     fn begin_scan(ctx: &Context) -> FdwResult {
         let this = Self::this_mut();
+        this.restore_server_credential();
 
         // Clear previous results
         this.scanned_profiles.clear();
         this.scan_index = 0;
+        this.scan_stats = ScanStats {
+            started_at: time::epoch_secs(),
+            ..Default::default()
+        };
+        this.scan_id = Some(this.new_scan_id(ctx));
+        this.count_only = ctx.get_columns().is_empty();
+        this.schema_drift = SchemaDrift::default();
+        this.public_fallback_urls.clear();
+        this.cache.frozen = false;
+        this.email_list = None;
 
         let opts = ctx.get_options(OptionsType::Table);
+        // table options only reach the FDW in begin_scan, check them once per init
+        if !this.table_options_checked {
+            Self::validate_option_names(&opts, Self::TABLE_OPTIONS, "table")?;
+            this.table_options_checked = true;
+        }
         let table = opts.require_or("table", Self::PROFILES_OBJECT);
+        this.use_table_api_url(&opts)?;
+        this.use_query_api_key(&ctx.get_quals())?;
 
-        if table != Self::PROFILES_OBJECT {
-            return Err(format!("Unsupported table '{}'. Only 'profiles' is supported.", table));
-        }
+        // Sensitive columns withheld whatever the grants, see iter_scan
+        this.blocked_columns = opts
+            .get("blocked_columns")
+            .map(|list| {
+                list.split(',')
+                    .map(|column| column.trim().to_lowercase())
+                    .filter(|column| !column.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
 
-        // Look for email filters in quals
-        let mut emails_to_fetch = Vec::new();
-        let quals = ctx.get_quals();
-        
-        for qual in quals {
-            if qual.field() == "email" {
-                if qual.operator() == "=" {
-                    if let Value::Cell(Cell::String(email)) = qual.value() {
-                        emails_to_fetch.push(email);
-                    }
-                } else {
-                    // Handle unsupported operators like IN, LIKE, etc.
-                    return Err(format!("Unsupported operator '{}' for email field. Only '=' (equality) is supported.", qual.operator()));
+        let columns: Vec<String> = ctx.get_columns().iter().map(|col| column_field(&col.name())).collect();
+
+        // The json column carries the whole payload, which least-privilege
+        // tables may want to withhold even from roles allowed to query them
+        this.hide_json = match opts.require_or("json_column", "enabled").as_str() {
+            "enabled" => false,
+            "null" => true,
+            "deny" => {
+                if columns.iter().any(|col| col == "json") {
+                    return Err("The json column is disabled on this table (json_column 'deny')".to_owned());
                 }
+                true
             }
+            other => return Err(format!("Invalid value '{}' for option 'json_column'. Expected 'enabled', 'null' or 'deny'.", other)),
+        };
+        this.sanitize_text = Self::bool_option(&opts, "sanitize_text", false)?;
+        this.nullify_empty_strings = Self::bool_option(&opts, "nullify_empty_strings", false)?;
+        this.nullify_empty_json = Self::bool_option(&opts, "nullify_empty_json", false)?;
+        if this.pseudonym_key.is_none() && columns.iter().any(|col| col == "pseudonym") {
+            return Err("The pseudonym column requires the pseudonym_key or pseudonym_key_id server option".to_owned());
         }
 
-        // If no email filter provided, we can't fetch profiles
-        if emails_to_fetch.is_empty() {
-            utils::report_info("No email filters provided. Gravatar FDW requires email = 'email@example.com' in WHERE clause");
-            return Ok(());
-        }
-
-        // Only allow one email at a time
-        if emails_to_fetch.len() > 1 {
-            return Err(format!("Multiple email filters are not supported. Found {} email conditions. Use separate queries for each email.", emails_to_fetch.len()));
-        }
-
-        // Fetch profiles for each email
-        for email in emails_to_fetch {
-            let url = this.build_url(&email);
-
-            let req = http::Request {
-                method: http::Method::Get,
-                url,
-                headers: this.headers.clone(),
-                body: String::default(),
-            };
-
-            let resp = http::get(&req)?;
-
-            // Handle 429 rate limiting
-            if resp.status_code == 429 {
-                // Check if we're using an API key
-                let using_api_key = this.headers.iter().any(|(key, _)| key.to_lowercase() == "authorization");
-
-                // Build error message based on X-RateLimit-Reset header and API key usage
-                let mut error_msg = "Rate limit exceeded (429).".to_string();
-
-                if let Some(reset_header) = resp.headers.iter().find(|h| h.0.to_lowercase() == "x-ratelimit-reset") {
-                    if let Ok(reset_timestamp) = reset_header.1.parse::<u64>() {
-                        let current_time = time::epoch_secs() as u64;
-                        let wait_seconds = if reset_timestamp > current_time {
-                            reset_timestamp - current_time
-                        } else {
-                            0
-                        };
-                        error_msg.push_str(&format!(" Wait {} seconds for reset.", wait_seconds));
-                    }
-                }
-
-                if using_api_key {
-                    error_msg.push_str(" Please contact Gravatar to increase your usage limit.");
-                } else {
-                    error_msg.push_str(" Consider getting an API key at https://gravatar.com/developers/applications for higher rate limits.");
-                }
-
-                return Err(error_msg);
-            }
+        this.max_rating = match opts.get("max_rating") {
+            Some(rating) => Some(AvatarRating::parse(rating.trim()).ok_or_else(|| {
+                format!("Invalid value '{}' for option 'max_rating'. Expected 'g', 'pg', 'r' or 'x'.", rating)
+            })?),
+            None => None,
+        };
+        this.null_over_rated_avatars = match opts.require_or("rating_action", "rewrite").as_str() {
+            "rewrite" => false,
+            "null" => true,
+            other => return Err(format!("Invalid value '{}' for option 'rating_action'. Expected 'rewrite' or 'null'.", other)),
+        };
 
-            if resp.status_code == 200 {
-                // Parse successful response
-                let mut profile: JsonValue = serde_json::from_str(&resp.body)
-                    .map_err(|e| format!("Failed to parse JSON response: {}", e))?;
+        this.fetch_inferred_interests = columns.iter().any(|col| col == "inferred_interests");
 
-                // Add email to the response since API doesn't return it
-                if let JsonValue::Object(ref mut map) = profile {
-                    map.insert("email".to_string(), JsonValue::String(email.clone()));
-                }
+        this.max_rows = match opts.get("max_rows") {
+            Some(_) => Some(Self::number_option(&opts, "max_rows", 0)?),
+            None => None,
+        };
 
-                this.scanned_profiles.push(profile);
-            } else {
-                // Handle 404 (expected for private or non-existing profiles) and generic API errors
-                // by skipping this email - no row will be returned for failed lookups
-                if resp.status_code == 404 {
-                    utils::report_info(&format!("Profile not found for email: {}", email));
-                } else {
-                    utils::report_info(&format!("HTTP error {} for email {}: {}", resp.status_code, email, resp.body));
-                }
-            }
+        if this.debug {
+            this.log_qual_pushdown(&table, &ctx.get_quals());
+        }
+        let scan = table_scan(&table).ok_or_else(|| unsupported_table_error(&table))?;
+        scan(this, ctx, &opts)?;
+        this.apply_max_rating();
+        this.apply_avatar_cdn();
+        if let Some(max_rows) = this.max_rows.filter(|max_rows| this.scanned_profiles.len() > *max_rows) {
+            this.log_warning(&format!(
+                "Returning only {} of {} rows (max_rows)",
+                max_rows,
+                this.scanned_profiles.len()
+            ));
+            this.scanned_profiles.truncate(max_rows);
         }
-
-        utils::report_info(&format!("Found {} profiles", this.scanned_profiles.len()));
-
         Ok(())
     }
 
@@ -219,53 +3705,110 @@ impl Guest for GravatarFdw {
             return Ok(None);
         }
 
-        let profile = &this.scanned_profiles[this.scan_index];
+        // Rows already emitted keep only their cells, see ScannedProfile::drain
+        if let Some(cells) = &this.scanned_profiles[this.scan_index].cells {
+            for cell in cells {
+                push_cell(row, cell.as_ref());
+            }
+            this.scan_index += 1;
+            return Ok(Some(0));
+        }
+
+        // count(*) only needs the number of rows, skip building cells
+        if this.count_only {
+            this.scanned_profiles[this.scan_index].drain(Vec::new());
+            this.scan_index += 1;
+            return Ok(Some(0));
+        }
+
+        // Blocked fields are also removed from the payload, so they can't
+        // leak through the json or vcard columns
+        if !this.blocked_columns.is_empty() {
+            let scanned = &mut this.scanned_profiles[this.scan_index];
+            if let JsonValue::Object(ref mut map) = scanned.profile {
+                for column in &this.blocked_columns {
+                    map.remove(column);
+                }
+            }
+            scanned.raw_body = None;
+        }
+
+        let scanned = &this.scanned_profiles[this.scan_index];
+        let profile = &scanned.profile;
 
-        for tgt_col in ctx.get_columns() {
-            let tgt_col_name = tgt_col.name();
+        let columns = ctx.get_columns();
+        let mut cells = Vec::with_capacity(columns.len());
+        for tgt_col in columns {
+            let tgt_col_name = column_field(&tgt_col.name());
             let cell = match tgt_col_name.as_str() {
-                "hash" => profile.get("hash").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                "email" => profile.get("email").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                "display_name" => profile.get("display_name").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                "profile_url" => profile.get("profile_url").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                "avatar_url" => profile.get("avatar_url").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                "avatar_alt_text" => profile.get("avatar_alt_text").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                "location" => profile.get("location").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                "description" => profile.get("description").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                "job_title" => profile.get("job_title").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                "company" => profile.get("company").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                "verified_accounts" => profile.get("verified_accounts").map(|v| Cell::Json(v.to_string())),
-                "pronunciation" => profile.get("pronunciation").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                "pronouns" => profile.get("pronouns").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                "timezone" => profile.get("timezone").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                "languages" => profile.get("languages").map(|v| Cell::Json(v.to_string())),
-                "first_name" => profile.get("first_name").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                "last_name" => profile.get("last_name").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                "is_organization" => profile.get("is_organization").and_then(|v| v.as_bool()).map(|s| Cell::Bool(s)),
-                "links" => profile.get("links").map(|v| Cell::Json(v.to_string())),
-                "interests" => profile.get("interests").map(|v| Cell::Json(v.to_string())),
-                "payments" => profile.get("payments").map(|v| Cell::Json(v.to_string())),
-                "contact_info" => profile.get("contact_info").map(|v| Cell::Json(v.to_string())),
-                "number_verified_accounts" => profile.get("number_verified_accounts").and_then(|v| v.as_i64()).map(|s| Cell::I64(s)),
-                "last_profile_edit" => profile.get("last_profile_edit").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())), // Postgres is converting string to timestamp.
-                "registration_date" => profile.get("registration_date").and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())), // Postgres is converting string to timestmap.
-                "json" => Some(Cell::Json(profile.to_string())),
-                _ => {
-                    // For unknown columns, try to get the value directly
-                    match tgt_col.type_oid() {
-                        TypeOid::Bool => profile.get(&tgt_col_name).and_then(|v| v.as_bool()).map(Cell::Bool),
-                        TypeOid::String => profile.get(&tgt_col_name).and_then(|v| v.as_str()).map(|s| Cell::String(s.to_string())),
-                        TypeOid::I32 => profile.get(&tgt_col_name).and_then(|v| v.as_i64()).map(|i| Cell::I32(i as i32)),
-                        TypeOid::I64 => profile.get(&tgt_col_name).and_then(|v| v.as_i64()).map(Cell::I64),
-                        TypeOid::Json => profile.get(&tgt_col_name).map(|v| Cell::Json(v.to_string())),
-                        _ => None,
-                    }
+                name if this.blocked_columns.iter().any(|column| column == name) => None,
+                "pseudonym" => profile
+                    .get("email")
+                    .and_then(|v| v.as_str())
+                    .and_then(|email| this.pseudonym(email))
+                    .map(Cell::String),
+                "json" if this.hide_json => None,
+                "json" => Some(Cell::Json(scanned.json())),
+                "parse_error" => Some(Cell::Bool(scanned.parse_error())),
+                "error" => scanned.error.clone().map(Cell::String),
+                "attempts" => Some(Cell::I32(scanned.fetch.attempts as i32)),
+                "ratelimit_limit" => scanned.fetch.header_i64("x-ratelimit-limit").map(Cell::I64),
+                "ratelimit_remaining" => scanned.fetch.header_i64("x-ratelimit-remaining").map(Cell::I64),
+                "ratelimit_reset" => scanned.fetch.header_i64("x-ratelimit-reset").map(Cell::I64),
+                "etag" => scanned.fetch.header("etag").map(|s| Cell::String(s.to_string())),
+                "content_language" => scanned.fetch.header("content-language").map(|s| Cell::String(s.to_string())),
+                "from_cache" => Some(Cell::Bool(scanned.fetch.from_cache)),
+                "response_headers" => Some(Cell::Json(this.response_headers_json(&scanned.fetch.headers))),
+                "changed_since_last_fetch" => {
+                    scanned.fetch.changed_fields.as_ref().map(|fields| Cell::Bool(!fields.is_empty()))
                 }
+                "changed_fields" => scanned
+                    .fetch
+                    .changed_fields
+                    .as_ref()
+                    .map(|fields| Cell::Json(JsonValue::Object(fields.clone()).to_string())),
+                "profile_source" => Some(Cell::String(this.profile_source(scanned).to_owned())),
+                "request_url" => Some(Cell::String(this.redact(&scanned.fetch.url))),
+                "vcard" => Some(Cell::String(profile_vcard(profile))),
+                "username" => profile_username(profile).map(Cell::String),
+                "fetched_at" => scanned.fetch.fetched_at.map(|secs| epoch_cell(secs, tgt_col.type_oid().into())),
+                "loaded_at" | "resets_at" | "last_error_at" => profile
+                    .get(&tgt_col_name)
+                    .and_then(|v| v.as_i64())
+                    .map(|secs| epoch_cell(secs, tgt_col.type_oid().into())),
+                // echo the max_age pseudo-qual so Postgres' recheck keeps the row
+                "max_age" => this.max_age.map(Cell::I64),
+                "emails" => this.email_list.clone().map(Cell::String),
+                // the secret's ID, never the key itself
+                "api_key_id" => this.query_api_key_id.clone().map(Cell::String),
+                "server_label" => this.server_label.clone().map(Cell::String),
+                name if this.nullify_empty_json
+                    && JSON_FIELDS.contains(&name)
+                    && profile.get(name).is_some_and(is_empty_json) =>
+                {
+                    None
+                }
+                _ => profile_cell(profile, &tgt_col_name, tgt_col.type_oid().into()),
+            };
+            let cell = match cell {
+                Some(Cell::String(value))
+                    if this.sanitize_text && SANITIZED_FIELDS.contains(&tgt_col_name.as_str()) =>
+                {
+                    Some(Cell::String(sanitize_text(&value)))
+                }
+                cell => cell,
+            };
+            let cell = match cell {
+                Some(Cell::String(value)) if value.is_empty() && this.nullify_empty_strings => None,
+                cell => cell,
             };
 
-            row.push(cell.as_ref());
+            push_cell(row, cell.as_ref());
+            cells.push(cell);
         }
 
+        // A 10k-row scan shouldn't hold every payload until end_scan
+        this.scanned_profiles[this.scan_index].drain(cells);
         this.scan_index += 1;
 
         Ok(Some(0))
@@ -279,31 +3822,154 @@ impl Guest for GravatarFdw {
 
     fn end_scan(_ctx: &Context) -> FdwResult {
         let this = Self::this_mut();
+
+        if this.json_metrics {
+            if this.scan_stats.total() > 0 || this.scan_stats.requests > 0 {
+                // unprefixed, so log pipelines can parse the whole message
+                let metrics = this.scan_stats.metrics_json(time::epoch_secs(), this.scan_id.as_deref());
+                utils::report_notice(&metrics.to_string());
+            }
+        } else if this.scan_stats.total() > 0 {
+            this.log_info(&format!(
+                "Gravatar scan finished: {} (status breakdown: {})",
+                this.scan_stats.summary(),
+                this.scan_stats.status.summary()
+            ));
+        }
+        if this.scan_stats.requests > 0 && !this.json_metrics {
+            this.log_info(&format!(
+                "Gravatar scan performance: {}",
+                this.scan_stats.performance_summary(time::epoch_secs())
+            ));
+        }
+        if let Some(warning) = this.schema_drift.warning() {
+            this.log_warning(&warning);
+        }
+        if this.api_keys.len() > 1 {
+            this.log_info(&format!("Gravatar scan served by API key #{} of {}", this.api_key_index + 1, this.api_keys.len()));
+        }
+        this.lifetime.add(&this.scan_stats);
+        this.report_telemetry();
+        this.flush_recording();
+
         this.scanned_profiles.clear();
         this.scan_index = 0;
+        this.scan_id = None;
+        this.restore_server_credential();
         Ok(())
     }
 
-    fn begin_modify(_ctx: &Context) -> FdwResult {
-        Err("modify on foreign table is not supported".to_owned())
-    }
+    fn begin_modify(ctx: &Context) -> FdwResult {
+        let this = Self::this_mut();
+        this.restore_server_credential();
+
+        let opts = ctx.get_options(OptionsType::Table);
+        let table = opts.require_or("table", Self::PROFILES_OBJECT);
+        this.use_table_api_url(&opts)?;
+        if (table == Self::ME_OBJECT || table == Self::AVATARS_OBJECT) && !this.is_authenticated() {
+            return Err(format!("Modifying the '{}' table requires an API key (api_key or api_key_id)", table));
+        }
+        if table == Self::PREFETCH_OBJECT && !this.cache.enabled() {
+            return Err("The 'prefetch' table requires caching. Set the cache_ttl server option.".to_owned());
+        }
+        let Some(rowid_column) = rowid_column(&table) else {
+            return Err("modify on foreign table is not supported".to_owned());
+        };
+        if opts.get("rowid_column").as_deref() != Some(rowid_column) {
+            return Err(format!(
+                "Modifying the '{}' table requires the table option rowid_column '{}'",
+                table, rowid_column
+            ));
+        }
+        this.modify_table = table;
+        this.pending_writes.clear();
+        this.email_hashes.clear();
 
-    fn insert(_ctx: &Context, _row: &Row) -> FdwResult {
         Ok(())
     }
 
-    fn update(_ctx: &Context, _rowid: Cell, _row: &Row) -> FdwResult {
+    fn insert(_ctx: &Context, row: &Row) -> FdwResult {
+        let this = Self::this_mut();
+        if this.modify_table != Self::PREFETCH_OBJECT {
+            return Err(format!("INSERT is not supported on the '{}' table", this.modify_table));
+        }
+
+        let email = row
+            .cols()
+            .into_iter()
+            .zip(row_cells(row))
+            .find_map(|(col, cell)| match (col.as_str(), cell) {
+                ("email", Some(Cell::String(email))) => Some(email),
+                _ => None,
+            })
+            .ok_or("INSERT into the 'prefetch' table requires an email")?;
+
+        this.track_email(&email);
+        this.pending_writes.push(PendingWrite::Prefetch { email });
         Ok(())
     }
 
-    fn delete(_ctx: &Context, _rowid: Cell) -> FdwResult {
-        Ok(())
+    fn update(_ctx: &Context, rowid: types::Cell, row: &Row) -> FdwResult {
+        let this = Self::this_mut();
+        let rowid = Cell::from(rowid);
+        match this.modify_table.as_str() {
+            Self::ME_OBJECT => {
+                let Cell::String(hash) = rowid else {
+                    return Err("UPDATE on the 'me' table requires rowid_column 'hash' of type text".to_owned());
+                };
+                let changes = Self::profile_changes(row)?;
+                if !changes.is_empty() {
+                    this.pending_writes.push(PendingWrite::UpdateProfile { hash, changes });
+                }
+                Ok(())
+            }
+            Self::AVATARS_OBJECT => {
+                let Cell::String(image_id) = rowid else {
+                    return Err("UPDATE on the 'avatars' table requires rowid_column 'image_id' of type text".to_owned());
+                };
+                // Only `selected = true` is supported
+                let selected = row
+                    .cols()
+                    .into_iter()
+                    .zip(row_cells(row))
+                    .find_map(|(col, cell)| match (col.as_str(), cell) {
+                        ("selected", Some(Cell::Bool(selected))) => Some(selected),
+                        _ => None,
+                    });
+                match selected {
+                    Some(true) => this.pending_writes.push(PendingWrite::SelectAvatar { image_id }),
+                    Some(false) => {
+                        return Err("An avatar can't be deselected. Select another avatar instead.".to_owned())
+                    }
+                    None => {}
+                }
+                Ok(())
+            }
+            table => Err(format!("UPDATE is not supported on the '{}' table", table)),
+        }
     }
 
-    fn end_modify(_ctx: &Context) -> FdwResult {
+    fn delete(_ctx: &Context, rowid: types::Cell) -> FdwResult {
+        let this = Self::this_mut();
+        let rowid = Cell::from(rowid);
+        if this.modify_table != Self::AVATARS_OBJECT {
+            return Err(format!("DELETE is not supported on the '{}' table", this.modify_table));
+        }
+
+        let Cell::String(image_id) = rowid else {
+            return Err("DELETE on the 'avatars' table requires rowid_column 'image_id' of type text".to_owned());
+        };
+
+        this.pending_writes.push(PendingWrite::DeleteAvatar { image_id });
         Ok(())
     }
 
+    fn end_modify(_ctx: &Context) -> FdwResult {
+        let this = Self::this_mut();
+        let result = this.flush_writes();
+        this.flush_recording();
+        result
+    }
 }
 
 bindings::export!(GravatarFdw with_types_in bindings);
@@ -0,0 +1,120 @@
+// Copyright 2025 Automattic
+//
+// This file is part of Gravatar Wasm Foreign Data Wrapper which is licensed under
+// the GNU General Public License v3.0.
+
+// Dispatch table from the 'table' option to the GravatarFdw scan filling
+// `scanned_profiles`. The scans and their state live on GravatarFdw; this only
+// picks one, so a new endpoint is a scan method, one entry in TABLE_SCANS and
+// its columns in fdw_core::TABLE_COLUMNS.
+
+use crate::bindings::supabase::wrappers::types::{Context, FdwResult, Options};
+use crate::GravatarFdw;
+
+// Runs the scan of one table. Options and the columns shared by every table
+// (json, pseudonym, blocked_columns) are already checked.
+pub(crate) type TableScan = fn(&mut GravatarFdw, &Context, &Options) -> FdwResult;
+
+// Which profile fields a profile table promises, see
+// GravatarFdw::check_profile_access
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum ProfileAccess {
+    // whatever the credentials allow, 'profiles'
+    Any,
+    // public fields only, whatever the credentials, 'profiles_public'
+    Public,
+    // every field, requires an API key, 'profiles_full'
+    Full,
+}
+
+// Profile lookups by email, the default table and its variants
+fn scan_profile_table(fdw: &mut GravatarFdw, ctx: &Context, opts: &Options, access: ProfileAccess) -> FdwResult {
+    fdw.check_profile_access(ctx, access)?;
+    if GravatarFdw::bool_option(opts, "exists_only", false)? {
+        return fdw.scan_exists(&ctx.get_quals());
+    }
+    fdw.scan_profiles(ctx, opts)?;
+    if access == ProfileAccess::Public {
+        fdw.strip_authenticated_fields();
+    }
+    Ok(())
+}
+
+// Every readable table, in the order error messages list them. 'prefetch' is
+// write-only, reading it returns no rows.
+pub(crate) const TABLE_SCANS: &[(&str, TableScan)] = &[
+    (GravatarFdw::PROFILES_OBJECT, |fdw, ctx, opts| scan_profile_table(fdw, ctx, opts, ProfileAccess::Any)),
+    (GravatarFdw::ME_OBJECT, |fdw, _, _| fdw.scan_me()),
+    (GravatarFdw::AVATARS_OBJECT, |fdw, _, _| fdw.scan_avatars()),
+    (GravatarFdw::PREFETCH_OBJECT, |_, _, _| Ok(())),
+    (GravatarFdw::QR_OBJECT, |fdw, ctx, opts| fdw.scan_qr(&ctx.get_quals(), opts)),
+    (GravatarFdw::SCHEMA_OBJECT, |fdw, _, _| {
+        fdw.scan_schema();
+        Ok(())
+    }),
+    (GravatarFdw::FIELDS_OBJECT, |fdw, _, _| {
+        fdw.scan_fields();
+        Ok(())
+    }),
+    (GravatarFdw::VERSION_OBJECT, |fdw, _, _| {
+        fdw.scan_version();
+        Ok(())
+    }),
+    (GravatarFdw::HEALTH_OBJECT, |fdw, _, _| {
+        fdw.scan_health();
+        Ok(())
+    }),
+    (GravatarFdw::STATS_OBJECT, |fdw, _, _| {
+        fdw.scan_lifetime_stats();
+        Ok(())
+    }),
+    (GravatarFdw::USAGE_OBJECT, |fdw, _, _| {
+        fdw.scan_usage();
+        Ok(())
+    }),
+    (GravatarFdw::ALL_INTERESTS_OBJECT, |fdw, ctx, _| fdw.scan_all_interests(&ctx.get_quals())),
+    (GravatarFdw::LINKS_OBJECT, |fdw, ctx, _| fdw.scan_links(&ctx.get_quals())),
+    (GravatarFdw::PROFILES_RAW_OBJECT, |fdw, ctx, _| fdw.scan_profiles_raw(&ctx.get_quals())),
+    (GravatarFdw::PROFILES_PUBLIC_OBJECT, |fdw, ctx, opts| scan_profile_table(fdw, ctx, opts, ProfileAccess::Public)),
+    (GravatarFdw::PROFILES_FULL_OBJECT, |fdw, ctx, opts| scan_profile_table(fdw, ctx, opts, ProfileAccess::Full)),
+    (GravatarFdw::CACHE_ENTRIES_OBJECT, |fdw, _, _| {
+        fdw.scan_cache_entries();
+        Ok(())
+    }),
+];
+
+pub(crate) fn table_scan(table: &str) -> Option<TableScan> {
+    TABLE_SCANS.iter().find(|(name, _)| *name == table).map(|(_, scan)| *scan)
+}
+
+// "Only 'profiles', 'me', ... and 'stats' are supported."
+pub(crate) fn unsupported_table_error(table: &str) -> String {
+    let names: Vec<String> = TABLE_SCANS.iter().map(|(name, _)| format!("'{}'", name)).collect();
+    let (last, rest) = names.split_last().expect("at least one table scan");
+    format!("Unsupported table '{}'. Only {} and {} are supported.", table, rest.join(", "), last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fdw_core::TABLE_COLUMNS;
+
+    #[test]
+    fn every_table_scan_has_its_columns() {
+        let scanned: Vec<&str> = TABLE_SCANS.iter().map(|(name, _)| *name).collect();
+        let described: Vec<&str> = TABLE_COLUMNS.iter().map(|(name, _)| *name).collect();
+        assert_eq!(scanned, described);
+    }
+
+    #[test]
+    fn unknown_tables_list_the_supported_ones() {
+        assert!(table_scan("profiles").is_some());
+        assert!(table_scan("interests").is_none());
+        assert_eq!(
+            unsupported_table_error("interests"),
+            "Unsupported table 'interests'. Only 'profiles', 'me', 'avatars', 'prefetch', 'qr', 'schema', 'fields', \
+             'version', 'health', 'stats', 'usage', 'all_interests', 'links', 'profiles_raw', \
+             'profiles_public', 'profiles_full' and 'cache_entries' are supported."
+        );
+    }
+}